@@ -6,9 +6,12 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+use crate::metadata::Metadata;
 use crate::solc::combined_json::contract::Contract as CombinedJsonContract;
 use crate::solc::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
 use crate::solc::standard_json::output::contract::Contract as StandardJsonOutputContract;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::source_map::SourceMap;
 
 ///
 /// The Solidity contract build.
@@ -21,8 +24,14 @@ pub struct Contract {
     pub identifier: String,
     /// The LLVM module build.
     pub build: compiler_llvm_context::Build,
+    /// The bytecode source map.
+    pub source_map: SourceMap,
     /// The ABI specification JSON.
     pub abi: Option<serde_json::Value>,
+    /// The contract metadata.
+    pub metadata: Option<Metadata>,
+    /// The warnings found during the translation.
+    pub warnings: Vec<SolcStandardJsonOutputError>,
 }
 
 impl Contract {
@@ -33,13 +42,19 @@ impl Contract {
         path: String,
         identifier: String,
         build: compiler_llvm_context::Build,
+        source_map: SourceMap,
         abi: Option<serde_json::Value>,
+        metadata: Option<Metadata>,
+        warnings: Vec<SolcStandardJsonOutputError>,
     ) -> Self {
         Self {
             path,
             identifier,
             build,
+            source_map,
             abi,
+            metadata,
+            warnings,
         }
     }
 
@@ -52,6 +67,7 @@ impl Contract {
         output_assembly: bool,
         output_binary: bool,
         output_abi: bool,
+        output_metadata: bool,
         overwrite: bool,
     ) -> anyhow::Result<()> {
         let file_name = Self::short_path(self.path.as_str());
@@ -128,6 +144,30 @@ impl Contract {
             }
         }
 
+        if let Some(metadata) = self.metadata {
+            if output_metadata {
+                let file_name = format!("{}_meta.{}", file_name, compiler_common::EXTENSION_JSON);
+                let mut file_path = path.to_owned();
+                file_path.push(file_name);
+
+                if file_path.exists() && !overwrite {
+                    eprintln!(
+                        "Refusing to overwrite an existing file {:?} (use --overwrite to force).",
+                        file_path
+                    );
+                } else {
+                    File::create(&file_path)
+                        .map_err(|error| {
+                            anyhow::anyhow!("File {:?} creating error: {}", file_path, error)
+                        })?
+                        .write_all(metadata.to_string().as_bytes())
+                        .map_err(|error| {
+                            anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
+                        })?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -156,6 +196,22 @@ impl Contract {
             (None, None) => {}
         }
 
+        if self.source_map.has_locations() {
+            let source_map = self.source_map.to_string();
+            if let Some(srcmap) = combined_json_contract.srcmap.as_mut() {
+                *srcmap = source_map.clone();
+            }
+            if let Some(srcmap_runtime) = combined_json_contract.srcmap_runtime.as_mut() {
+                *srcmap_runtime = source_map;
+            }
+        }
+
+        if let (Some(metadata), Some(combined_json_metadata)) =
+            (self.metadata, combined_json_contract.metadata.as_mut())
+        {
+            *combined_json_metadata = metadata.to_string();
+        }
+
         combined_json_contract.abi = self.abi;
         combined_json_contract.factory_deps = Some(self.build.factory_dependencies);
 
@@ -171,10 +227,18 @@ impl Contract {
     ) -> anyhow::Result<()> {
         let bytecode = hex::encode(self.build.bytecode.as_slice());
 
+        let source_map = if self.source_map.has_locations() {
+            Some(self.source_map.to_string())
+        } else {
+            None
+        };
+
         standard_json_contract.ir_optimized = None;
         standard_json_contract.abi = self.abi;
-        standard_json_contract.evm =
-            Some(StandardJsonOutputContractEVM::new_zkevm_bytecode(bytecode));
+        standard_json_contract.metadata = self.metadata.map(|metadata| metadata.to_string());
+        standard_json_contract.evm = Some(StandardJsonOutputContractEVM::new_zkevm_bytecode(
+            bytecode, source_map,
+        ));
         standard_json_contract.factory_dependencies = Some(self.build.factory_dependencies);
         standard_json_contract.hash = Some(self.build.hash);
 
@@ -0,0 +1,89 @@
+//!
+//! The Solidity project build info.
+//!
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::metadata::hash::Hash as MetadataHash;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::Input as SolcStandardJsonInput;
+
+///
+/// The Solidity project build info.
+///
+/// Contains all inputs which affect the bytecode, so the project can be recompiled and verified.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Info {
+    /// The `zksolc` version.
+    pub zk_version: semver::Version,
+    /// The `solc` version.
+    pub solc_version: semver::Version,
+    /// The `solc` long version.
+    pub solc_long_version: String,
+    /// The `solc` pipeline.
+    pub pipeline: SolcPipeline,
+    /// The LLVM optimizer mode, that is, `cycles` or `none`.
+    pub optimizer_mode: String,
+    /// The LLVM options.
+    pub llvm_options: Vec<String>,
+    /// The metadata hash type appended to the bytecode.
+    pub metadata_hash: MetadataHash,
+    /// The library addresses.
+    pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// The standard JSON input sent to `solc`.
+    pub input: SolcStandardJsonInput,
+    /// The contract bytecode hashes.
+    pub hashes: BTreeMap<String, String>,
+}
+
+impl Info {
+    ///
+    /// Reads the build info from the JSON file.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path).map_err(|error| {
+            anyhow::anyhow!("Build info file {:?} reading error: {}", path, error)
+        })?;
+        serde_json::from_str(json.as_str())
+            .map_err(|error| anyhow::anyhow!("Build info file {:?} parsing error: {}", path, error))
+    }
+
+    ///
+    /// Whether the LLVM optimizer is enabled.
+    ///
+    pub fn is_optimized(&self) -> bool {
+        self.optimizer_mode == "cycles"
+    }
+
+    ///
+    /// Writes the build info to the specified directory, returning the file path.
+    ///
+    /// The file is named after the build info hash, so the same compilation is always written to
+    /// the same file.
+    ///
+    pub fn write_to_directory(&self, output_directory: &Path) -> anyhow::Result<PathBuf> {
+        let json = serde_json::to_vec(self).expect("Always valid");
+        let mut file_path = output_directory.to_owned();
+        file_path.push(format!(
+            "{}.{}",
+            compiler_llvm_context::keccak256(json.as_slice()),
+            compiler_common::EXTENSION_JSON
+        ));
+
+        File::create(&file_path)
+            .map_err(|error| anyhow::anyhow!("File {:?} creating error: {}", file_path, error))?
+            .write_all(json.as_slice())
+            .map_err(|error| anyhow::anyhow!("File {:?} writing error: {}", file_path, error))?;
+
+        Ok(file_path)
+    }
+}
@@ -3,15 +3,23 @@
 //!
 
 pub mod contract;
+pub mod info;
 
 use std::collections::BTreeMap;
 use std::path::Path;
+use std::path::PathBuf;
 
+use crate::metadata::hash::Hash as MetadataHash;
+use crate::size_report::contract::Contract as SizeReportContract;
+use crate::size_report::SizeReport;
 use crate::solc::combined_json::CombinedJson;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::Input as StandardJsonInput;
 use crate::solc::standard_json::output::Output as StandardJsonOutput;
 use crate::solc::version::Version as SolcVersion;
 
 use self::contract::Contract;
+use self::info::Info;
 
 ///
 /// The Solidity project build.
@@ -23,6 +31,76 @@ pub struct Build {
 }
 
 impl Build {
+    ///
+    /// Assembles the zkEVM assembly files.
+    ///
+    /// The contracts are named after the files without the extension.
+    ///
+    pub fn try_from_zkasm(paths: &[PathBuf]) -> anyhow::Result<Self> {
+        let mut contracts = BTreeMap::new();
+        for path in paths.iter() {
+            let assembly_text = std::fs::read_to_string(path)
+                .map_err(|error| anyhow::anyhow!("File {:?} reading error: {}", path, error))?;
+            let contract_path = path.with_extension("").to_string_lossy().to_string();
+            let contract = crate::zkasm::assemble(contract_path.clone(), assembly_text)?;
+            contracts.insert(contract_path, contract);
+        }
+
+        Ok(Self { contracts })
+    }
+
+    ///
+    /// Returns the build info with the contract hashes and the inputs they are built from.
+    ///
+    /// The library addresses are taken from the `solc` input settings. The LLVM optimizer mode
+    /// is passed separately, since the `solc` optimizer is always enabled in the CLI mode.
+    ///
+    pub fn build_info(
+        &self,
+        input: StandardJsonInput,
+        solc_version: &SolcVersion,
+        pipeline: SolcPipeline,
+        is_optimized: bool,
+        llvm_options: Vec<String>,
+        metadata_hash: MetadataHash,
+    ) -> Info {
+        Info {
+            zk_version: semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid"),
+            solc_version: solc_version.default.to_owned(),
+            solc_long_version: solc_version.long.to_owned(),
+            pipeline,
+            optimizer_mode: if is_optimized { "cycles" } else { "none" }.to_owned(),
+            llvm_options,
+            metadata_hash,
+            libraries: input.settings.libraries.clone().unwrap_or_default(),
+            input,
+            hashes: self
+                .contracts
+                .iter()
+                .map(|(path, contract)| (path.to_owned(), contract.build.hash.to_owned()))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Returns the size report of all contracts.
+    ///
+    /// If `with_functions` is set, the instructions of each contract are also counted by function.
+    ///
+    pub fn size_report(&self, with_functions: bool) -> SizeReport {
+        SizeReport::new(
+            self.contracts
+                .iter()
+                .map(|(path, contract)| {
+                    (
+                        path.to_owned(),
+                        SizeReportContract::new(&contract.build, with_functions),
+                    )
+                })
+                .collect(),
+        )
+    }
+
     ///
     /// Writes all contracts to the specified directory.
     ///
@@ -32,6 +110,7 @@ impl Build {
         output_assembly: bool,
         output_binary: bool,
         output_abi: bool,
+        output_metadata: bool,
         overwrite: bool,
     ) -> anyhow::Result<()> {
         for (_path, contract) in self.contracts.into_iter() {
@@ -40,6 +119,7 @@ impl Build {
                 output_assembly,
                 output_binary,
                 output_abi,
+                output_metadata,
                 overwrite,
             )?;
         }
@@ -81,12 +161,26 @@ impl Build {
     ///
     /// Writes all contracts assembly and bytecode to the standard JSON.
     ///
+    /// The warnings found during the translation are appended to the standard JSON errors.
+    ///
     pub fn write_to_standard_json(
         mut self,
         standard_json: &mut StandardJsonOutput,
         solc_version: &SolcVersion,
         zksolc_version: &semver::Version,
     ) -> anyhow::Result<()> {
+        let warnings: Vec<_> = self
+            .contracts
+            .values_mut()
+            .flat_map(|contract| contract.warnings.drain(..))
+            .collect();
+        if !warnings.is_empty() {
+            standard_json
+                .errors
+                .get_or_insert_with(Vec::new)
+                .extend(warnings);
+        }
+
         let contracts = match standard_json.contracts.as_mut() {
             Some(contracts) => contracts,
             None => return Ok(()),
@@ -109,3 +203,52 @@ impl Build {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::build::info::Info;
+    use crate::metadata::hash::Hash as MetadataHash;
+    use crate::solc::pipeline::Pipeline as SolcPipeline;
+    use crate::solc::standard_json::input::Input as StandardJsonInput;
+    use crate::solc::version::Version as SolcVersion;
+
+    use super::Build;
+
+    #[test]
+    fn build_info_optimizer_mode() {
+        let build = Build {
+            contracts: BTreeMap::new(),
+        };
+        let solc_version = SolcVersion::new(
+            "0.8.17+commit.8df45f5f".to_owned(),
+            semver::Version::new(0, 8, 17),
+        );
+        let input = StandardJsonInput::try_from_sources(
+            BTreeMap::new(),
+            BTreeMap::new(),
+            serde_json::json!({}),
+            true,
+        )
+        .expect("Always valid");
+
+        for is_optimized in [true, false] {
+            let build_info = build.build_info(
+                input.clone(),
+                &solc_version,
+                SolcPipeline::Yul,
+                is_optimized,
+                vec![],
+                MetadataHash::None,
+            );
+            let json = serde_json::to_string(&build_info).expect("Always valid");
+            let build_info: Info = serde_json::from_str(json.as_str()).expect("Always valid");
+            assert_eq!(build_info.is_optimized(), is_optimized);
+            assert_eq!(
+                build_info.optimizer_mode,
+                if is_optimized { "cycles" } else { "none" }
+            );
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//!
+//! The EVM compatibility report.
+//!
+
+pub mod substitution;
+pub mod yul_visitor;
+
+use std::collections::BTreeMap;
+
+use self::substitution::Substitution;
+
+///
+/// The EVM compatibility report.
+///
+/// Lists the places where the EVM semantics is changed by the translation to zkEVM, so they can
+/// be reviewed explicitly.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CompatReport {
+    /// The substitutions by the contract path.
+    pub contracts: BTreeMap<String, Vec<Substitution>>,
+}
+
+impl CompatReport {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(contracts: BTreeMap<String, Vec<Substitution>>) -> Self {
+        Self { contracts }
+    }
+}
+
+impl std::fmt::Display for CompatReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (path, substitutions) in self.contracts.iter() {
+            if substitutions.is_empty() {
+                writeln!(
+                    f,
+                    "Contract `{}` EVM compatibility report: no substitutions",
+                    path
+                )?;
+                continue;
+            }
+
+            writeln!(f, "Contract `{}` EVM compatibility report:", path)?;
+            for substitution in substitutions.iter() {
+                writeln!(f, "    {}", substitution)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+//!
+//! The EVM semantics substitution.
+//!
+
+///
+/// The EVM semantics substitution, which is made by the translator silently, or only with
+/// `--evmla-lenient` for the instructions unsupported by zkEVM.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution {
+    /// The substituted instruction name.
+    pub instruction: String,
+    /// The description of the zkEVM behavior.
+    pub description: &'static str,
+    /// The best available location of the instruction.
+    pub location: String,
+    /// Whether the substitution is only made with `--evmla-lenient`, and is a compile error
+    /// otherwise.
+    pub is_lenient_only: bool,
+}
+
+impl Substitution {
+    /// The `CODESIZE` substitution description.
+    pub const CODESIZE: &'static str = "is replaced with the calldata size";
+
+    /// The `PUSHSIZE` and `PC` substitution description.
+    pub const ZERO: &'static str = "is replaced with 0";
+
+    /// The `CALLCODE` substitution description.
+    pub const CALLCODE: &'static str = "is not performed and returns 0";
+
+    /// The `EXTCODECOPY` and `SELFDESTRUCT` substitution description.
+    pub const NO_OPERATION: &'static str = "is ignored";
+
+    /// The `PUSH data` longer than 32 bytes substitution description.
+    pub const PUSH_DATA: &'static str = "with the data longer than 32 bytes is replaced with 0";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        instruction: String,
+        description: &'static str,
+        location: String,
+        is_lenient_only: bool,
+    ) -> Self {
+        Self {
+            instruction,
+            description,
+            location,
+            is_lenient_only,
+        }
+    }
+}
+
+impl std::fmt::Display for Substitution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_lenient_only {
+            write!(
+                f,
+                "{}: `{}` is a compile error, and with `--evmla-lenient` {}",
+                self.location, self.instruction, self.description
+            )
+        } else {
+            write!(
+                f,
+                "{}: `{}` {}",
+                self.location, self.instruction, self.description
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Substitution;
+
+    #[test]
+    fn display() {
+        let silent = Substitution::new(
+            "CODESIZE".to_owned(),
+            Substitution::CODESIZE,
+            "Test.sol:3:9".to_owned(),
+            false,
+        );
+        assert_eq!(
+            silent.to_string(),
+            "Test.sol:3:9: `CODESIZE` is replaced with the calldata size"
+        );
+
+        let lenient_only = Substitution::new(
+            "PC".to_owned(),
+            Substitution::ZERO,
+            "Test.sol:4:9".to_owned(),
+            true,
+        );
+        assert_eq!(
+            lenient_only.to_string(),
+            "Test.sol:4:9: `PC` is a compile error, and with `--evmla-lenient` is replaced with 0"
+        );
+    }
+}
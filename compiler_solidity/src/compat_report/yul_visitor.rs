@@ -0,0 +1,106 @@
+//!
+//! The EVM compatibility report Yul visitor.
+//!
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+
+///
+/// The EVM compatibility report Yul visitor.
+///
+/// Finds the calls of the built-in functions whose translation changes the EVM semantics, that
+/// is, `codesize` and `callcode`.
+///
+#[derive(Debug, Default)]
+pub struct YulVisitor {
+    /// The calls found so far.
+    calls: Vec<(Location, FunctionName)>,
+}
+
+impl YulVisitor {
+    ///
+    /// Returns the substituted calls in the object and its inner objects, in the order of their
+    /// appearance.
+    ///
+    pub fn substitutions(object: &Object) -> Vec<(Location, FunctionName)> {
+        let mut visitor = Self::default();
+        visitor.object(object);
+        visitor.calls
+    }
+
+    ///
+    /// Visits the object and its inner object.
+    ///
+    fn object(&mut self, object: &Object) {
+        self.block(&object.code.block);
+        if let Some(ref inner_object) = object.inner_object {
+            self.object(inner_object);
+        }
+    }
+
+    ///
+    /// Visits the block statements.
+    ///
+    fn block(&mut self, block: &Block) {
+        for statement in block.statements.iter() {
+            self.statement(statement);
+        }
+    }
+
+    ///
+    /// Visits the statement.
+    ///
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Object(object) => self.object(object),
+            Statement::Code(code) => self.block(&code.block),
+            Statement::Block(block) => self.block(block),
+            Statement::Expression(expression) => self.expression(expression),
+            Statement::FunctionDefinition(function) => self.block(&function.body),
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(ref expression) = declaration.expression {
+                    self.expression(expression);
+                }
+            }
+            Statement::Assignment(assignment) => self.expression(&assignment.initializer),
+            Statement::IfConditional(conditional) => {
+                self.expression(&conditional.condition);
+                self.block(&conditional.block);
+            }
+            Statement::Switch(switch) => {
+                self.expression(&switch.expression);
+                for case in switch.cases.iter() {
+                    self.block(&case.block);
+                }
+                if let Some(ref default) = switch.default {
+                    self.block(default);
+                }
+            }
+            Statement::ForLoop(r#loop) => {
+                self.block(&r#loop.initializer);
+                self.expression(&r#loop.condition);
+                self.block(&r#loop.finalizer);
+                self.block(&r#loop.body);
+            }
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Visits the expression.
+    ///
+    fn expression(&mut self, expression: &Expression) {
+        if let Expression::FunctionCall(call) = expression {
+            if let FunctionName::CodeSize | FunctionName::CallCode = call.name {
+                self.calls.push((call.location, call.name.clone()));
+            }
+            for argument in call.arguments.iter() {
+                self.expression(argument);
+            }
+        }
+    }
+}
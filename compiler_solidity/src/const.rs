@@ -15,3 +15,6 @@ pub const OFFSET_EMPTY_SLOT: usize = 3 * compiler_common::SIZE_FIELD;
 
 /// The non-reserved memory offset.
 pub const OFFSET_NON_RESERVED: usize = 4 * compiler_common::SIZE_FIELD;
+
+/// The maximum zkEVM bytecode size in words.
+pub const BYTECODE_MAX_WORDS: usize = (1 << 16) - 1;
@@ -0,0 +1,294 @@
+//!
+//! The LLVM debug information.
+//!
+
+pub mod write_debug_llvm;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use inkwell::debug_info::AsDIScope;
+use inkwell::debug_info::DIFlagsConstants;
+
+///
+/// The LLVM debug information emitter.
+///
+/// Attaches the source code positions to the LLVM IR while it is generated: the statement and
+/// instruction translators set the builder debug location before emitting their code, so every
+/// instruction gets the position of the Yul statement or EVM legacy assembly instruction it is
+/// translated from.
+///
+/// The LLVM IR generator context does not carry the emitter, so it is passed to the translators
+/// explicitly with the `WriteDebugLLVM` trait.
+///
+pub struct DebugInfo<'ctx> {
+    /// The LLVM context.
+    llvm: &'ctx inkwell::context::Context,
+    /// The debug information builder.
+    builder: inkwell::debug_info::DebugInfoBuilder<'ctx>,
+    /// The contract compile unit.
+    compile_unit: inkwell::debug_info::DICompileUnit<'ctx>,
+    /// The contract path, which is the file of the function subprograms.
+    path: String,
+    /// The files by their paths.
+    files: RefCell<BTreeMap<String, inkwell::debug_info::DIFile<'ctx>>>,
+    /// The function subprograms by the LLVM function names.
+    subprograms: RefCell<BTreeMap<String, inkwell::debug_info::DISubprogram<'ctx>>>,
+    /// The function scopes of the files other than the subprogram one.
+    file_scopes: RefCell<BTreeMap<(String, String), inkwell::debug_info::DIScope<'ctx>>>,
+}
+
+impl<'ctx> DebugInfo<'ctx> {
+    /// The LLVM debug information metadata version.
+    pub const METADATA_VERSION: u64 = 3;
+
+    /// The debug information producer.
+    pub const PRODUCER: &'static str = "zksolc";
+
+    ///
+    /// Creates the compile unit of the contract at `path` in the `module`.
+    ///
+    pub fn new(
+        llvm: &'ctx inkwell::context::Context,
+        module: &inkwell::module::Module<'ctx>,
+        path: &str,
+    ) -> Self {
+        module.add_basic_value_flag(
+            "Debug Info Version",
+            inkwell::module::FlagBehavior::Warning,
+            llvm.i32_type().const_int(Self::METADATA_VERSION, false),
+        );
+
+        let (builder, compile_unit) = module.create_debug_info_builder(
+            true,
+            inkwell::debug_info::DWARFSourceLanguage::C,
+            path,
+            "",
+            Self::PRODUCER,
+            false,
+            "",
+            0,
+            "",
+            inkwell::debug_info::DWARFEmissionKind::LineTablesOnly,
+            0,
+            false,
+            false,
+            "",
+            "",
+        );
+
+        Self {
+            llvm,
+            builder,
+            compile_unit,
+            path: path.to_owned(),
+            files: RefCell::new(BTreeMap::new()),
+            subprograms: RefCell::new(BTreeMap::new()),
+            file_scopes: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    ///
+    /// Resolves the debug information, which must be done before the module is optimized.
+    ///
+    pub fn finalize(&self) {
+        self.builder.finalize();
+    }
+
+    ///
+    /// Enters the current LLVM function of the `context`, positioning the builder at its `line`.
+    ///
+    pub fn set_function_location<D>(
+        &self,
+        context: &compiler_llvm_context::Context<'ctx, D>,
+        line: usize,
+    ) where
+        D: compiler_llvm_context::Dependency,
+    {
+        self.locate_function(context.builder(), context.function().value, line);
+    }
+
+    ///
+    /// Positions the builder of the `context` at the `line` and `column` of the `file`, or of the
+    /// contract itself if `file` is `None`.
+    ///
+    pub fn set_location<D>(
+        &self,
+        context: &compiler_llvm_context::Context<'ctx, D>,
+        file: Option<&str>,
+        line: usize,
+        column: usize,
+    ) where
+        D: compiler_llvm_context::Dependency,
+    {
+        self.locate(
+            context.builder(),
+            context.function().value,
+            file,
+            line,
+            column,
+        );
+    }
+
+    ///
+    /// Clears the builder position before the code is generated into another LLVM function.
+    ///
+    pub fn unset_location<D>(&self, context: &compiler_llvm_context::Context<'ctx, D>)
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        context.builder().unset_current_debug_location();
+    }
+
+    ///
+    /// Enters the `function`, creating its subprogram at `line`, and positions the builder at the
+    /// beginning of the function.
+    ///
+    pub fn locate_function(
+        &self,
+        builder: &inkwell::builder::Builder<'ctx>,
+        function: inkwell::values::FunctionValue<'ctx>,
+        line: usize,
+    ) {
+        let subprogram = self.subprogram(function, line);
+        let location = self.builder.create_debug_location(
+            self.llvm,
+            line as u32,
+            0,
+            subprogram.as_debug_info_scope(),
+            None,
+        );
+        builder.set_current_debug_location(self.llvm, location);
+    }
+
+    ///
+    /// Positions the builder at the `line` and `column` of the `file`, or of the contract itself
+    /// if `file` is `None`, within the `function`.
+    ///
+    /// The line 0 marks the compiler-generated code.
+    ///
+    pub fn locate(
+        &self,
+        builder: &inkwell::builder::Builder<'ctx>,
+        function: inkwell::values::FunctionValue<'ctx>,
+        file: Option<&str>,
+        line: usize,
+        column: usize,
+    ) {
+        let subprogram = self.subprogram(function, line);
+        let scope = match file {
+            Some(file) if file != self.path.as_str() => self.file_scope(function, subprogram, file),
+            _ => subprogram.as_debug_info_scope(),
+        };
+        let location =
+            self.builder
+                .create_debug_location(self.llvm, line as u32, column as u32, scope, None);
+        builder.set_current_debug_location(self.llvm, location);
+    }
+
+    ///
+    /// Returns the `function` subprogram, creating it at `line` if the function is entered for
+    /// the first time.
+    ///
+    fn subprogram(
+        &self,
+        function: inkwell::values::FunctionValue<'ctx>,
+        line: usize,
+    ) -> inkwell::debug_info::DISubprogram<'ctx> {
+        let name = function.get_name().to_string_lossy().to_string();
+        if let Some(subprogram) = self.subprograms.borrow().get(name.as_str()) {
+            return *subprogram;
+        }
+
+        let file = self.file(self.path.as_str());
+        let subroutine_type = self.builder.create_subroutine_type(
+            file,
+            None,
+            &[],
+            inkwell::debug_info::DIFlags::PUBLIC,
+        );
+        let subprogram = self.builder.create_function(
+            self.compile_unit.as_debug_info_scope(),
+            name.as_str(),
+            None,
+            file,
+            line as u32,
+            subroutine_type,
+            true,
+            true,
+            line as u32,
+            inkwell::debug_info::DIFlags::PUBLIC,
+            false,
+        );
+        function.set_subprogram(subprogram);
+        self.subprograms.borrow_mut().insert(name, subprogram);
+        subprogram
+    }
+
+    ///
+    /// Returns the scope of the `file` within the `function`, since the locations take their
+    /// files from their scopes.
+    ///
+    fn file_scope(
+        &self,
+        function: inkwell::values::FunctionValue<'ctx>,
+        subprogram: inkwell::debug_info::DISubprogram<'ctx>,
+        file: &str,
+    ) -> inkwell::debug_info::DIScope<'ctx> {
+        let key = (
+            function.get_name().to_string_lossy().to_string(),
+            file.to_owned(),
+        );
+        if let Some(scope) = self.file_scopes.borrow().get(&key) {
+            return *scope;
+        }
+
+        let scope = self
+            .builder
+            .create_lexical_block(subprogram.as_debug_info_scope(), self.file(file), 0, 0)
+            .as_debug_info_scope();
+        self.file_scopes.borrow_mut().insert(key, scope);
+        scope
+    }
+
+    ///
+    /// Returns the file, creating it if it is used for the first time.
+    ///
+    fn file(&self, path: &str) -> inkwell::debug_info::DIFile<'ctx> {
+        *self
+            .files
+            .borrow_mut()
+            .entry(path.to_owned())
+            .or_insert_with(|| self.builder.create_file(path, ""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugInfo;
+
+    #[test]
+    fn locate() {
+        let llvm = inkwell::context::Context::create();
+        let module = llvm.create_module("Test");
+        let builder = llvm.create_builder();
+        let debug_info = DebugInfo::new(&llvm, &module, "Test.sol:Test");
+
+        let function = module.add_function("test", llvm.void_type().fn_type(&[], false), None);
+        builder.position_at_end(llvm.append_basic_block(function, "entry"));
+        debug_info.locate_function(&builder, function, 2);
+        let pointer = builder.build_alloca(llvm.i64_type(), "value");
+        debug_info.locate(&builder, function, None, 3, 9);
+        builder.build_store(pointer, llvm.i64_type().const_int(42, false));
+        debug_info.locate(&builder, function, Some("Test.sol"), 7, 5);
+        builder.build_return(None);
+        debug_info.finalize();
+
+        assert!(module.verify().is_ok());
+        let ir = module.print_to_string().to_string();
+        assert!(ir.contains("DISubprogram(name: \"test\""));
+        assert!(ir.contains("DILocation(line: 3, column: 9"));
+        assert!(ir.contains("DILocation(line: 7, column: 5"));
+        assert!(ir.contains("DIFile(filename: \"Test.sol\""));
+    }
+}
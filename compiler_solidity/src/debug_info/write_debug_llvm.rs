@@ -0,0 +1,26 @@
+//!
+//! The LLVM IR generator with the debug information.
+//!
+
+use crate::debug_info::DebugInfo;
+
+///
+/// The LLVM IR generator with the debug information.
+///
+/// Implemented by the entities which attach the source code positions to the generated code, and
+/// by the ones containing them. The debug information emitter is `None` if the debug information
+/// is not requested.
+///
+pub trait WriteDebugLLVM<'ctx, D>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    ///
+    /// Translates the entity into LLVM IR, attaching the positions with `debug_info`.
+    ///
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()>;
+}
@@ -0,0 +1,39 @@
+//!
+//! The compiler diagnostic collection.
+//!
+
+use crate::diagnostic::Diagnostic;
+
+///
+/// The compiler diagnostic collection.
+///
+/// Is used as an `anyhow` error, so the diagnostics can be recovered via downcasting and
+/// printed in the machine-readable format.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collection {
+    /// The diagnostics.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Collection {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(diagnostics: Vec<Diagnostic>) -> Self {
+        Self { diagnostics }
+    }
+}
+
+impl std::error::Error for Collection {}
+
+impl std::fmt::Display for Collection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rendered: Vec<String> = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.render(true))
+            .collect();
+        write!(f, "{}", rendered.join("\n"))
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The compiler diagnostic output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The compiler diagnostic output format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The human-readable text with source code snippets.
+    Human,
+    /// One JSON object per line.
+    JSON,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::JSON),
+            value => anyhow::bail!(
+                "Invalid error format `{}`. Available formats: human, json",
+                value
+            ),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//!
+//! The compiler diagnostic message.
+//!
+
+pub mod collection;
+pub mod format;
+pub mod span;
+
+use colored::Colorize;
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::yul::error::Error as YulError;
+
+use self::span::Span;
+
+///
+/// The compiler diagnostic message, rendered in the `rustc` style with a source code snippet.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The severity, e.g. `error` or `warning`.
+    pub severity: String,
+    /// The error or warning code, if it is known.
+    pub code: Option<String>,
+    /// The message.
+    pub message: String,
+    /// The source code file path.
+    pub path: String,
+    /// The start and end byte offsets in the source code file, if they are known.
+    pub offsets: Option<(usize, usize)>,
+    /// The highlighted source code fragment, if it is known.
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(severity: String, message: String, path: String, span: Option<Span>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message,
+            path,
+            offsets: None,
+            span,
+        }
+    }
+
+    ///
+    /// Creates a diagnostic from the Yul lexer, parser, or semantic analyzer error.
+    ///
+    pub fn new_yul(path: &str, source_code: &str, error: &YulError) -> Self {
+        let location = error.location();
+        let message = error
+            .to_string()
+            .replacen(format!("{} ", location).as_str(), "", 1);
+        let span =
+            Span::from_line_column(source_code, location.line, location.column, error.length());
+
+        let offsets = span.as_ref().map(|span| span.offsets(source_code));
+
+        Self {
+            offsets,
+            ..Self::new("error".to_owned(), message, path.to_owned(), span)
+        }
+    }
+
+    ///
+    /// Creates a diagnostic from the `solc` standard JSON output error.
+    ///
+    /// The `source_code` is the content of the file the error location points to.
+    ///
+    pub fn new_solc(error: &SolcStandardJsonOutputError, source_code: Option<&str>) -> Self {
+        let (path, offsets, span) = match error.source_location.as_ref() {
+            Some(location) => (
+                location.file.to_owned(),
+                usize::try_from(location.start)
+                    .ok()
+                    .zip(usize::try_from(location.end).ok()),
+                source_code.and_then(|source_code| {
+                    Span::from_offsets(source_code, location.start, location.end)
+                }),
+            ),
+            None => (String::new(), None, None),
+        };
+
+        Self {
+            code: error.error_code.to_owned(),
+            offsets,
+            ..Self::new(
+                error.severity.to_owned(),
+                error.message.trim().to_owned(),
+                path,
+                span,
+            )
+        }
+    }
+
+    ///
+    /// Creates an error diagnostic without the source code location.
+    ///
+    pub fn new_error(message: String) -> Self {
+        Self::new("error".to_owned(), message, String::new(), None)
+    }
+
+    ///
+    /// Renders the diagnostic.
+    ///
+    /// If `is_colored` is set, the output is colored unless it is disabled globally, e.g. if the
+    /// output is not a terminal, or via the `NO_COLOR` environment variable.
+    ///
+    pub fn render(&self, is_colored: bool) -> String {
+        let paint = |text: String, color: colored::Color| -> String {
+            if is_colored {
+                text.color(color).bold().to_string()
+            } else {
+                text
+            }
+        };
+        let severity_color = match self.severity.as_str() {
+            "error" => colored::Color::Red,
+            "warning" => colored::Color::Yellow,
+            _ => colored::Color::Cyan,
+        };
+
+        let mut result = format!(
+            "{}: {}\n",
+            paint(self.severity.to_owned(), severity_color),
+            self.message.trim()
+        );
+
+        let span = match self.span {
+            Some(ref span) => span,
+            None => {
+                if !self.path.is_empty() {
+                    result.push_str(
+                        format!(
+                            " {} {}\n",
+                            paint("-->".to_owned(), colored::Color::Blue),
+                            self.path
+                        )
+                        .as_str(),
+                    );
+                }
+                return result;
+            }
+        };
+
+        let gutter = " ".repeat(span.line.to_string().len());
+        result.push_str(
+            format!(
+                "{}{} {}:{}:{}\n",
+                gutter,
+                paint("-->".to_owned(), colored::Color::Blue),
+                self.path,
+                span.line,
+                span.column,
+            )
+            .as_str(),
+        );
+        result.push_str(
+            format!(
+                "{} {}\n",
+                gutter,
+                paint("|".to_owned(), colored::Color::Blue)
+            )
+            .as_str(),
+        );
+        result.push_str(
+            format!(
+                "{} {} {}\n",
+                paint(span.line.to_string(), colored::Color::Blue),
+                paint("|".to_owned(), colored::Color::Blue),
+                span.text,
+            )
+            .as_str(),
+        );
+        result.push_str(
+            format!(
+                "{} {} {}{}\n",
+                gutter,
+                paint("|".to_owned(), colored::Color::Blue),
+                span.indent(),
+                paint("^".repeat(span.length), severity_color),
+            )
+            .as_str(),
+        );
+
+        result
+    }
+
+    ///
+    /// Serializes the diagnostic into a single-line JSON object.
+    ///
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "severity": self.severity,
+            "code": self.code,
+            "message": self.message.trim(),
+            "file": self.path,
+            "start": self.offsets.map(|(start, _end)| start),
+            "end": self.offsets.map(|(_start, end)| end),
+            "line": self.span.as_ref().map(|span| span.line),
+            "column": self.span.as_ref().map(|span| span.column),
+        })
+        .to_string()
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
@@ -0,0 +1,162 @@
+//!
+//! The diagnostic source code span.
+//!
+
+///
+/// The highlighted source code fragment.
+///
+/// Multi-line fragments are truncated to their first line.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// The line number, starting from 1.
+    pub line: usize,
+    /// The column number, starting from 1.
+    pub column: usize,
+    /// The number of highlighted characters.
+    pub length: usize,
+    /// The text of the line.
+    pub text: String,
+}
+
+impl Span {
+    ///
+    /// Creates a span from the line and column, e.g. taken from a Yul lexer location.
+    ///
+    /// Returns `None` if the line does not exist in `source_code`.
+    ///
+    pub fn from_line_column(
+        source_code: &str,
+        line: usize,
+        column: usize,
+        length: usize,
+    ) -> Option<Self> {
+        let text = source_code.lines().nth(line.checked_sub(1)?)?;
+        Some(Self::new(line, column, length, text))
+    }
+
+    ///
+    /// Creates a span from the byte offsets, e.g. taken from a `solc` source location.
+    ///
+    /// Returns `None` if the offsets are negative or do not point to `source_code` characters.
+    ///
+    pub fn from_offsets(source_code: &str, start: isize, end: isize) -> Option<Self> {
+        let start = usize::try_from(start).ok()?;
+        let end = usize::try_from(end).ok()?.max(start);
+
+        let prefix = source_code.get(..start)?;
+        let line = prefix.matches('\n').count() + 1;
+        let line_start = prefix
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        let column = prefix[line_start..].chars().count() + 1;
+        let length = source_code
+            .get(start..end)
+            .map(|fragment| fragment.chars().count())
+            .unwrap_or_default();
+        let text = source_code[line_start..].lines().next().unwrap_or_default();
+
+        Some(Self::new(line, column, length, text))
+    }
+
+    ///
+    /// A shortcut constructor, which truncates the highlighted fragment to the line end.
+    ///
+    fn new(line: usize, column: usize, length: usize, text: &str) -> Self {
+        let remaining = text
+            .chars()
+            .count()
+            .saturating_sub(column.saturating_sub(1));
+
+        Self {
+            line,
+            column,
+            length: length.min(remaining).max(1),
+            text: text.to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the start and end byte offsets of the highlighted fragment in `source_code`.
+    ///
+    pub fn offsets(&self, source_code: &str) -> (usize, usize) {
+        let line_start: usize = source_code
+            .split_inclusive('\n')
+            .take(self.line.saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let mut characters = self.text.chars();
+        let prefix: usize = characters
+            .by_ref()
+            .take(self.column.saturating_sub(1))
+            .map(char::len_utf8)
+            .sum();
+        let fragment: usize = characters.take(self.length).map(char::len_utf8).sum();
+
+        let start = line_start + prefix;
+        (start, start + fragment)
+    }
+
+    ///
+    /// Returns the whitespace preceding the highlighted fragment.
+    ///
+    /// The tabs are preserved, so the highlighting is aligned with the text of the line.
+    ///
+    pub fn indent(&self) -> String {
+        self.text
+            .chars()
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .chain(std::iter::repeat(' '))
+            .take(self.column.saturating_sub(1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::span::Span;
+
+    #[test]
+    fn from_offsets() {
+        let source_code = "contract Test {\n\tfunction f() public {}\n}\n";
+        let start = source_code.find("function").expect("Always exists") as isize;
+
+        assert_eq!(
+            Span::from_offsets(source_code, start, start + 10),
+            Some(Span {
+                line: 2,
+                column: 2,
+                length: 10,
+                text: "\tfunction f() public {}".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn from_offsets_multi_line() {
+        let source_code = "contract Test {\n}\n";
+
+        let span =
+            Span::from_offsets(source_code, 9, source_code.len() as isize).expect("Always exists");
+        assert_eq!(span.line, 1);
+        assert_eq!(span.column, 10);
+        assert_eq!(span.length, 6);
+        assert_eq!(span.indent(), " ".repeat(9));
+    }
+
+    #[test]
+    fn offsets() {
+        let source_code = "contract Test {\n\tfunction f() public {}\n}\n";
+        let start = source_code.find("function").expect("Always exists");
+
+        let span = Span::from_line_column(source_code, 2, 2, 8).expect("Always exists");
+        assert_eq!(span.offsets(source_code), (start, start + 8));
+    }
+
+    #[test]
+    fn from_offsets_out_of_bounds() {
+        assert_eq!(Span::from_offsets("contract Test {}", -1, 0), None);
+        assert_eq!(Span::from_offsets("contract Test {}", 100, 101), None);
+    }
+}
@@ -13,6 +13,8 @@ pub enum DumpFlag {
     Yul,
     /// Whether to dump the Ethereal IR code.
     EthIR,
+    /// Whether to dump the Ethereal IR block duplication statistics.
+    EthIRStats,
     /// Whether to dump the EVM code.
     EVM,
     /// Whether to dump the LLVM IR code.
@@ -28,17 +30,21 @@ impl DumpFlag {
     pub fn from_booleans(
         yul: bool,
         ethir: bool,
+        ethir_stats: bool,
         evm: bool,
         llvm: bool,
         assembly: bool,
     ) -> Vec<Self> {
-        let mut vector = Vec::with_capacity(5);
+        let mut vector = Vec::with_capacity(6);
         if yul {
             vector.push(Self::Yul);
         }
         if ethir {
             vector.push(Self::EthIR);
         }
+        if ethir_stats {
+            vector.push(Self::EthIRStats);
+        }
         if evm {
             vector.push(Self::EVM);
         }
@@ -58,6 +64,7 @@ impl DumpFlag {
         Self::from_booleans(
             dump_flags.contains(&compiler_llvm_context::DumpFlag::Yul),
             dump_flags.contains(&compiler_llvm_context::DumpFlag::EthIR),
+            false,
             dump_flags.contains(&compiler_llvm_context::DumpFlag::EVM),
             dump_flags.contains(&compiler_llvm_context::DumpFlag::LLVM),
             dump_flags.contains(&compiler_llvm_context::DumpFlag::Assembly),
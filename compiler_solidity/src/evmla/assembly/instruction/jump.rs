@@ -2,6 +2,9 @@
 //! Translates the jump operations.
 //!
 
+use inkwell::types::BasicType;
+use inkwell::values::BasicValue;
+
 ///
 /// Translates the unconditional jump.
 ///
@@ -67,3 +70,134 @@ where
 
     Ok(None)
 }
+
+///
+/// Translates the recovered function call.
+///
+/// The `stack_height` includes the function destination tag, which is preceded by the arguments
+/// and the return tag. The return values replace the return tag and the arguments.
+///
+pub fn call<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    name: &str,
+    stack_height: usize,
+    input_size: usize,
+    output_size: usize,
+    return_tag: num::BigUint,
+    return_stack_hash: md5::Digest,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let function = context
+        .functions
+        .get(name)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Undeclared function `{}`", name))?;
+    let return_address_index = stack_height - input_size - 2;
+
+    let mut arguments = Vec::with_capacity(input_size + 1);
+    if let Some(compiler_llvm_context::FunctionReturn::Compound { size, .. }) = function.r#return {
+        let r#type = context.structure_type(vec![context.field_type().as_basic_type_enum(); size]);
+        let pointer =
+            context.build_alloca(r#type, format!("{}_return_pointer_argument", name).as_str());
+        context.build_store(pointer, r#type.const_zero());
+        arguments.push(pointer.as_basic_value_enum());
+    }
+    for index in 0..input_size {
+        let pointer = context.evm().stack[return_address_index + 1 + index]
+            .to_llvm()
+            .into_pointer_value();
+        let argument = context.build_load(pointer, format!("{}_argument_{}", name, index).as_str());
+        arguments.push(argument);
+    }
+
+    let return_value = context.build_invoke(
+        function.value,
+        arguments.as_slice(),
+        format!("{}_call", name).as_str(),
+    );
+
+    if output_size > 1 {
+        let return_pointer = return_value.expect("Always exists").into_pointer_value();
+        for index in 0..output_size {
+            let pointer = unsafe {
+                context.builder().build_gep(
+                    return_pointer,
+                    &[
+                        context.field_const(0),
+                        context
+                            .integer_type(compiler_common::BITLENGTH_X32)
+                            .const_int(index as u64, false),
+                    ],
+                    format!("{}_return_value_{}_gep_pointer", name, index).as_str(),
+                )
+            };
+            let value =
+                context.build_load(pointer, format!("{}_return_value_{}", name, index).as_str());
+            let pointer = context.evm().stack[return_address_index + index]
+                .to_llvm()
+                .into_pointer_value();
+            context.build_store(pointer, value);
+        }
+    } else if let Some(value) = return_value {
+        let pointer = context.evm().stack[return_address_index]
+            .to_llvm()
+            .into_pointer_value();
+        context.build_store(pointer, value);
+    }
+
+    unconditional(context, return_tag, return_stack_hash)
+}
+
+///
+/// Translates the recovered function return.
+///
+/// The return values are the `output_size` elements at the bottom of the function stack.
+///
+pub fn r#return<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    output_size: usize,
+) -> anyhow::Result<Option<inkwell::values::BasicValueEnum<'ctx>>>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    match context.function().r#return {
+        Some(compiler_llvm_context::FunctionReturn::Primitive { pointer }) => {
+            let value_pointer = context.evm().stack[0].to_llvm().into_pointer_value();
+            let value = context.build_load(value_pointer, "return_value");
+            context.build_store(pointer, value);
+        }
+        Some(compiler_llvm_context::FunctionReturn::Compound { pointer, .. }) => {
+            for index in 0..output_size {
+                let field_pointer = unsafe {
+                    context.builder().build_gep(
+                        pointer,
+                        &[
+                            context.field_const(0),
+                            context
+                                .integer_type(compiler_common::BITLENGTH_X32)
+                                .const_int(index as u64, false),
+                        ],
+                        format!("return_{}_gep_pointer", index).as_str(),
+                    )
+                };
+                let field_pointer = context.builder().build_pointer_cast(
+                    field_pointer,
+                    context
+                        .field_type()
+                        .ptr_type(compiler_llvm_context::AddressSpace::Stack.into()),
+                    format!("return_{}_gep_pointer_field", index).as_str(),
+                );
+                let value_pointer = context.evm().stack[index].to_llvm().into_pointer_value();
+                let value =
+                    context.build_load(value_pointer, format!("return_value_{}", index).as_str());
+                context.build_store(field_pointer, value);
+            }
+        }
+        _ => {}
+    }
+    context.build_unconditional_branch(context.function().return_block);
+
+    Ok(None)
+}
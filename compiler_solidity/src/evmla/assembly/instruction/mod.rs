@@ -12,17 +12,43 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::compat_report::substitution::Substitution;
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+use crate::source_map::line_index::LineIndex;
+
 use self::name::Name;
 
 ///
 /// The EVM instruction.
 ///
+/// The Solidity source code location and jump type are not serialized, so they do not affect
+/// the contract hash.
+///
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Instruction {
     /// The opcode or tag identifier.
     pub name: Name,
     /// The optional value argument.
     pub value: Option<String>,
+
+    /// The Solidity source code start byte offset.
+    #[serde(skip_serializing)]
+    pub begin: Option<isize>,
+    /// The Solidity source code end byte offset.
+    #[serde(skip_serializing)]
+    pub end: Option<isize>,
+    /// The Solidity source code file index in the `sources` list.
+    #[serde(skip_serializing)]
+    pub source: Option<isize>,
+    /// The jump type, e.g. `[in]` for function calls and `[out]` for returns.
+    #[serde(rename = "jumpType", skip_serializing)]
+    pub jump_type: Option<String>,
+    /// The Solidity source code location resolved against the `sources` list.
+    #[serde(skip)]
+    pub location: Option<SourceLocation>,
+    /// The Solidity source code line and column of the location start, both starting from 1.
+    #[serde(skip)]
+    pub line_column: Option<(usize, usize)>,
 }
 
 impl Instruction {
@@ -270,6 +296,7 @@ impl Instruction {
                 Instruction {
                     name: Name::PUSH_ContractHash | Name::PUSH_ContractHashSize,
                     value: Some(value),
+                    ..
                 } => {
                     *value = mapping
                         .get(value.as_str())
@@ -279,6 +306,7 @@ impl Instruction {
                 Instruction {
                     name: Name::PUSH_Data,
                     value: Some(value),
+                    ..
                 } => {
                     let mut key_extended =
                         "0".repeat(compiler_common::SIZE_FIELD * 2 - value.len());
@@ -295,14 +323,108 @@ impl Instruction {
         Ok(())
     }
 
+    ///
+    /// Returns the EVM semantics substitution description, if the translation changes it.
+    ///
+    pub fn compat_substitution(&self) -> Option<&'static str> {
+        match self.name {
+            Name::CODESIZE => Some(Substitution::CODESIZE),
+            Name::PUSHSIZE | Name::PC => Some(Substitution::ZERO),
+            Name::CALLCODE => Some(Substitution::CALLCODE),
+            Name::EXTCODECOPY | Name::SELFDESTRUCT => Some(Substitution::NO_OPERATION),
+            Name::PUSH_Data if self.is_push_data_oversized() => Some(Substitution::PUSH_DATA),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Checks that the instruction is supported by zkEVM.
+    ///
+    /// If `is_lenient` is set, the unsupported instructions are accepted, as they are translated
+    /// with the EVM semantics substitutions.
+    ///
+    pub fn check_supported(&self, is_lenient: bool) -> anyhow::Result<()> {
+        if is_lenient {
+            return Ok(());
+        }
+
+        match self.name {
+            Name::PC | Name::CALLCODE | Name::EXTCODECOPY | Name::SELFDESTRUCT => {
+                anyhow::bail!(
+                    "The `{}` instruction{} is not supported",
+                    self.name.to_string().trim(),
+                    self.unresolved_location(),
+                )
+            }
+            Name::PUSH_Data if self.is_push_data_oversized() => anyhow::bail!(
+                "The `PUSH data` value of {} bytes{} is not supported",
+                self.value.as_ref().map(String::len).unwrap_or_default() / 2,
+                self.unresolved_location(),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Returns the raw `solc` location of the instruction for the error messages, if it has not
+    /// been resolved to a Solidity source code location, which is reported by the caller.
+    ///
+    fn unresolved_location(&self) -> String {
+        match (self.location.as_ref(), self.begin, self.end, self.source) {
+            (None, Some(begin), Some(end), Some(source)) => {
+                format!(" at bytes {}..{} of source {}", begin, end, source)
+            }
+            _ => String::new(),
+        }
+    }
+
+    ///
+    /// Whether the instruction is a `PUSH data` with the data longer than 32 bytes.
+    ///
+    fn is_push_data_oversized(&self) -> bool {
+        self.value
+            .as_ref()
+            .map(|value| value.len() > compiler_common::SIZE_FIELD * 2)
+            .unwrap_or_default()
+    }
+
     ///
     /// Initializes an INVALID instruction to terminate an invalid unreachable block part.
     ///
     pub fn invalid() -> Self {
-        Self {
-            name: Name::INVALID,
-            value: None,
-        }
+        Self::from(Name::INVALID)
+    }
+
+    ///
+    /// Resolves the Solidity source code location against the `solc` output `sources` list,
+    /// which maps the source code file indexes to their paths.
+    ///
+    /// The location is left unset for the compiler-generated code, where `solc` sets the file
+    /// index to `-1`. The line and column are only set if the file is in `line_indexes`.
+    ///
+    pub fn resolve_location(
+        &mut self,
+        source_paths: &BTreeMap<usize, String>,
+        line_indexes: &BTreeMap<String, LineIndex>,
+    ) {
+        self.location = match (self.begin, self.end, self.source) {
+            (Some(begin), Some(end), Some(source)) if begin >= 0 && end >= begin => {
+                usize::try_from(source)
+                    .ok()
+                    .and_then(|source| source_paths.get(&source))
+                    .map(|path| SourceLocation {
+                        file: path.to_owned(),
+                        start: begin,
+                        end,
+                    })
+            }
+            _ => None,
+        };
+        self.line_column = self.location.as_ref().and_then(|location| {
+            line_indexes
+                .get(location.file.as_str())
+                .map(|line_index| line_index.line_column(location.start as usize))
+        });
     }
 }
 
@@ -312,10 +434,58 @@ impl std::fmt::Display for Instruction {
             f,
             "{:16}{:64}",
             self.name,
-            match self.value {
-                Some(ref value) => value.as_str(),
-                None => "",
+            match (self.value.as_ref(), self.jump_type.as_ref()) {
+                (Some(value), _) => value.as_str(),
+                (None, Some(jump_type)) => jump_type.as_str(),
+                (None, None) => "",
             }
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::name::Name;
+    use super::Instruction;
+
+    #[test]
+    fn check_supported() {
+        for name in [
+            Name::PC,
+            Name::CALLCODE,
+            Name::EXTCODECOPY,
+            Name::SELFDESTRUCT,
+        ] {
+            let instruction = Instruction::from(name);
+            assert!(instruction.check_supported(false).is_err());
+            assert!(instruction.check_supported(true).is_ok());
+        }
+
+        let mut push_data = Instruction::from(Name::PUSH_Data);
+        push_data.value = Some("ff".repeat(compiler_common::SIZE_FIELD));
+        assert!(push_data.check_supported(false).is_ok());
+        push_data.value = Some("ff".repeat(compiler_common::SIZE_FIELD + 1));
+        assert!(push_data.check_supported(false).is_err());
+        assert!(push_data.check_supported(true).is_ok());
+
+        assert!(Instruction::from(Name::CODESIZE)
+            .check_supported(false)
+            .is_ok());
+    }
+
+    #[test]
+    fn check_supported_unresolved_location() {
+        let mut instruction = Instruction::from(Name::SELFDESTRUCT);
+        instruction.begin = Some(10);
+        instruction.end = Some(20);
+        instruction.source = Some(3);
+
+        let error = instruction
+            .check_supported(false)
+            .expect_err("Always invalid");
+        assert_eq!(
+            error.to_string(),
+            "The `SELFDESTRUCT` instruction at bytes 10..20 of source 3 is not supported"
+        );
+    }
+}
@@ -354,7 +354,17 @@ pub enum Name {
 
 impl From<Name> for Instruction {
     fn from(name: Name) -> Self {
-        Self { name, value: None }
+        Self {
+            name,
+            value: None,
+
+            begin: None,
+            end: None,
+            source: None,
+            jump_type: None,
+            location: None,
+            line_column: None,
+        }
     }
 }
 
@@ -7,12 +7,23 @@ pub mod instruction;
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::path::PathBuf;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::build::contract::Contract as ContractBuild;
+use crate::compat_report::substitution::Substitution;
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::evmla::ethereal_ir::entry_link::EntryLink;
+use crate::evmla::ethereal_ir::function::cfg::format::Format as CFGFormat;
+use crate::evmla::ethereal_ir::function::cfg::CFG;
+use crate::evmla::ethereal_ir::function::truncation::Truncation;
 use crate::evmla::ethereal_ir::EtherealIR;
+use crate::source_map::debug_locations::DebugLocations;
+use crate::source_map::entry::Entry as SourceMapEntry;
+use crate::source_map::line_index::LineIndex;
 
 use self::data::Data;
 use self::instruction::name::Name as InstructionName;
@@ -39,6 +50,24 @@ pub struct Assembly {
     /// The factory dependency paths.
     #[serde(skip)]
     pub factory_dependencies: HashSet<String>,
+    /// Whether the unsupported instructions are translated leniently instead of failing.
+    #[serde(skip)]
+    pub is_lenient: bool,
+    /// The maximum number of the Ethereal IR block clones per function, if limited.
+    #[serde(skip)]
+    pub ethir_clone_budget: Option<usize>,
+    /// Whether the Ethereal IR block duplication statistics are printed.
+    #[serde(skip)]
+    pub is_ethir_stats_dumped: bool,
+    /// The Ethereal IR control flow graph format and the directory it is written to.
+    #[serde(skip)]
+    pub ethir_cfg_dump: Option<(CFGFormat, PathBuf)>,
+    /// Whether the Ethereal IR block truncations are errors instead of warnings.
+    #[serde(skip)]
+    pub is_ethir_strict: bool,
+    /// The Ethereal IR, which is built in the declaration pass.
+    #[serde(skip)]
+    pub ethereal_ir: Option<EtherealIR>,
 }
 
 impl Assembly {
@@ -57,6 +86,52 @@ impl Assembly {
         self.full_path = Some(full_path);
     }
 
+    ///
+    /// Sets whether the unsupported instructions are translated leniently instead of failing.
+    ///
+    pub fn set_lenient(&mut self, is_lenient: bool) {
+        self.is_lenient = is_lenient;
+    }
+
+    ///
+    /// Sets the maximum number of the Ethereal IR block clones per function.
+    ///
+    pub fn set_ethir_clone_budget(&mut self, ethir_clone_budget: Option<usize>) {
+        self.ethir_clone_budget = ethir_clone_budget;
+    }
+
+    ///
+    /// Sets whether the Ethereal IR block duplication statistics are printed.
+    ///
+    pub fn set_ethir_stats_dumped(&mut self, is_ethir_stats_dumped: bool) {
+        self.is_ethir_stats_dumped = is_ethir_stats_dumped;
+    }
+
+    ///
+    /// Sets the Ethereal IR control flow graph format and the directory it is written to.
+    ///
+    pub fn set_ethir_cfg_dump(&mut self, ethir_cfg_dump: Option<(CFGFormat, PathBuf)>) {
+        self.ethir_cfg_dump = ethir_cfg_dump;
+    }
+
+    ///
+    /// Sets whether the Ethereal IR block truncations are errors instead of warnings.
+    ///
+    pub fn set_ethir_strict(&mut self, is_ethir_strict: bool) {
+        self.is_ethir_strict = is_ethir_strict;
+    }
+
+    ///
+    /// Returns the blocks truncated as unreachable by the Ethereal IR built in the declaration
+    /// pass, which are reported as warnings.
+    ///
+    pub fn ethir_truncations(&self) -> Vec<Truncation> {
+        self.ethereal_ir
+            .as_ref()
+            .map(EtherealIR::truncations)
+            .unwrap_or_default()
+    }
+
     ///
     /// Returns the full contract path if it is set, or `<undefined>` otherwise.
     ///
@@ -69,6 +144,123 @@ impl Assembly {
             .unwrap_or_else(|| panic!("The full path of some contracts is unset"))
     }
 
+    ///
+    /// Resolves the Solidity source code locations of the instructions, including the ones of
+    /// the inner assemblies, against the `solc` output `sources` list.
+    ///
+    pub fn resolve_source_locations(
+        &mut self,
+        source_paths: &BTreeMap<usize, String>,
+        line_indexes: &BTreeMap<String, LineIndex>,
+    ) {
+        for instruction in self.code.iter_mut().flatten() {
+            instruction.resolve_location(source_paths, line_indexes);
+        }
+        for data in self.data.iter_mut().flat_map(|data| data.values_mut()) {
+            if let Some(assembly) = data.get_assembly_mut() {
+                assembly.resolve_source_locations(source_paths, line_indexes);
+            }
+        }
+    }
+
+    ///
+    /// Returns the debug locations table of the deploy and runtime code, which maps the Solidity
+    /// lines and columns back to the source code ranges.
+    ///
+    pub fn debug_locations(&self) -> DebugLocations {
+        let runtime_code = self
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .and_then(Data::get_assembly)
+            .and_then(|assembly| assembly.code.as_deref());
+
+        let mut debug_locations = DebugLocations::default();
+        for instruction in [self.code.as_deref(), runtime_code]
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            if let (Some(location), Some((line, column)), Some(source)) = (
+                instruction.location.as_ref(),
+                instruction.line_column,
+                instruction.source,
+            ) {
+                debug_locations.insert(
+                    location.file.as_str(),
+                    line,
+                    column,
+                    SourceMapEntry::new(
+                        location.start as usize,
+                        (location.end - location.start) as usize,
+                        source as usize,
+                    ),
+                );
+            }
+        }
+        debug_locations
+    }
+
+    ///
+    /// Returns the factory dependency assemblies of the deploy and runtime code, which are not
+    /// resolved into the contract paths yet.
+    ///
+    pub fn dependencies(&self) -> Vec<&Assembly> {
+        let runtime_code_data = self
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .and_then(Data::get_assembly)
+            .and_then(|assembly| assembly.data.as_ref());
+
+        let mut dependencies = Vec::new();
+        for (index, data) in self.data.iter().flatten() {
+            if index == "0" {
+                continue;
+            }
+            dependencies.extend(data.get_assembly());
+        }
+        for data in runtime_code_data.into_iter().flat_map(|data| data.values()) {
+            dependencies.extend(data.get_assembly());
+        }
+        dependencies
+    }
+
+    ///
+    /// Returns the EVM semantics substitutions made in the deploy and runtime code.
+    ///
+    pub fn compat_substitutions(&self) -> Vec<Substitution> {
+        let runtime_code = self
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .and_then(Data::get_assembly)
+            .and_then(|assembly| assembly.code.as_deref());
+
+        let mut substitutions = Vec::new();
+        for (code_type, instructions) in
+            [("deploy", self.code.as_deref()), ("runtime", runtime_code)]
+        {
+            for (index, instruction) in instructions.into_iter().flatten().enumerate() {
+                if let Some(description) = instruction.compat_substitution() {
+                    let location = match instruction.location {
+                        Some(ref location) => {
+                            format!("{} ({} code, instruction {})", location, code_type, index)
+                        }
+                        None => format!("{} code, instruction {}", code_type, index),
+                    };
+                    substitutions.push(Substitution::new(
+                        instruction.name.to_string().trim().to_owned(),
+                        description,
+                        location,
+                        instruction.check_supported(false).is_err(),
+                    ));
+                }
+            }
+        }
+        substitutions
+    }
+
     ///
     /// Replaces the deploy code dependencies with full contract path and returns the list.
     ///
@@ -191,10 +383,6 @@ where
 
         entry.into_llvm(context)?;
 
-        Ok(())
-    }
-
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         let full_path = self.full_path().to_owned();
 
         if context.has_dump_flag(compiler_llvm_context::DumpFlag::EVM) {
@@ -202,6 +390,7 @@ where
         }
         let deploy_code_blocks = EtherealIR::get_blocks(
             context.evm().version.to_owned(),
+            self.is_lenient,
             compiler_llvm_context::CodeType::Deploy,
             self.code
                 .as_deref()
@@ -210,15 +399,16 @@ where
 
         let data = self
             .data
-            .ok_or_else(|| anyhow::anyhow!("Runtime code data not found"))?
-            .remove("0")
-            .expect("Always exists");
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .ok_or_else(|| anyhow::anyhow!("Runtime code data not found"))?;
         if context.has_dump_flag(compiler_llvm_context::DumpFlag::EVM) {
             println!("Contract `{}` runtime EVM:\n\n{}", full_path, data);
         };
         let runtime_code_instructions = match data {
             Data::Assembly(assembly) => assembly
                 .code
+                .as_deref()
                 .ok_or_else(|| anyhow::anyhow!("Runtime code instructions not found"))?,
             Data::Hash(hash) => {
                 anyhow::bail!("Expected runtime code instructions, found hash `{}`", hash)
@@ -229,18 +419,76 @@ where
         };
         let runtime_code_blocks = EtherealIR::get_blocks(
             context.evm().version.to_owned(),
+            self.is_lenient,
             compiler_llvm_context::CodeType::Runtime,
-            runtime_code_instructions.as_slice(),
+            runtime_code_instructions,
         )?;
 
         let mut blocks = deploy_code_blocks;
         blocks.extend(runtime_code_blocks);
-        let mut ethereal_ir = EtherealIR::new(context.evm().version.to_owned(), blocks)?;
+        let ethereal_ir = EtherealIR::new(
+            context.evm().version.to_owned(),
+            blocks,
+            self.ethir_clone_budget,
+        )?;
         if context.has_dump_flag(compiler_llvm_context::DumpFlag::EthIR) {
             println!("Contract `{}` Ethereal IR:\n\n{}", full_path, ethereal_ir);
         }
+        let truncations = ethereal_ir.truncations();
+        if self.is_ethir_strict && !truncations.is_empty() {
+            anyhow::bail!(
+                "The Ethereal IR blocks are truncated as unreachable:\n{}",
+                truncations
+                    .iter()
+                    .map(|truncation| format!("    {}", truncation))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+        }
+        if self.is_ethir_stats_dumped {
+            println!(
+                "Contract `{}` Ethereal IR statistics:\n\n{}",
+                full_path,
+                ethereal_ir.stats()
+            );
+        }
+        if let Some((format, directory)) = self.ethir_cfg_dump.clone() {
+            std::fs::create_dir_all(&directory).map_err(|error| {
+                anyhow::anyhow!("Directory {:?} creating error: {}", directory, error)
+            })?;
+            let mut file_path = directory;
+            file_path.push(format!(
+                "{}.ethir.{}",
+                ContractBuild::short_path(full_path.as_str()),
+                format.extension()
+            ));
+            let cfg = CFG::new(&ethereal_ir).render(format, full_path.as_str());
+            std::fs::write(&file_path, cfg).map_err(|error| {
+                anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
+            })?;
+        }
+        self.ethereal_ir = Some(ethereal_ir);
+
+        Ok(())
+    }
+
+    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Assembly
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
+        let mut ethereal_ir = self.ethereal_ir.expect("Always exists");
         ethereal_ir.declare(context)?;
-        ethereal_ir.into_llvm(context)?;
+        ethereal_ir.into_llvm_debug(context, debug_info)?;
 
         compiler_llvm_context::DeployCodeFunction::new(EntryLink::new(
             compiler_llvm_context::CodeType::Deploy,
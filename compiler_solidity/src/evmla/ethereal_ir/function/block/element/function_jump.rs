@@ -0,0 +1,58 @@
+//!
+//! The Ethereal IR block element function jump.
+//!
+
+///
+/// The Ethereal IR block element function jump.
+///
+/// Marks the `JUMP` instructions recognized as the recovered function calls and returns.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionJump {
+    /// The recovered function call.
+    Call {
+        /// The called function name.
+        name: String,
+        /// The number of the function arguments.
+        input_size: usize,
+        /// The number of the function return values.
+        output_size: usize,
+        /// The return destination tag.
+        return_tag: num::BigUint,
+        /// The stack hash at the return destination.
+        return_stack_hash: md5::Digest,
+    },
+    /// The recovered function return.
+    Return {
+        /// The number of the function return values.
+        output_size: usize,
+    },
+}
+
+impl FunctionJump {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call(
+        name: String,
+        input_size: usize,
+        output_size: usize,
+        return_tag: num::BigUint,
+        return_stack_hash: md5::Digest,
+    ) -> Self {
+        Self::Call {
+            name,
+            input_size,
+            output_size,
+            return_tag,
+            return_stack_hash,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn r#return(output_size: usize) -> Self {
+        Self::Return { output_size }
+    }
+}
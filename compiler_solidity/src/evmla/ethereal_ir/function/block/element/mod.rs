@@ -2,6 +2,7 @@
 //! The Ethereal IR block element.
 //!
 
+pub mod function_jump;
 pub mod stack;
 
 use inkwell::values::BasicValue;
@@ -10,6 +11,7 @@ use crate::evmla::assembly::instruction::codecopy;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
 
+use self::function_jump::FunctionJump;
 use self::stack::Stack;
 
 ///
@@ -19,21 +21,27 @@ use self::stack::Stack;
 pub struct Element {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
+    /// Whether the unsupported instructions are translated leniently instead of failing.
+    pub is_lenient: bool,
     /// The instruction.
     pub instruction: Instruction,
     /// The stack data.
     pub stack: Stack,
+    /// The recovered function call or return, if the element is such a `JUMP`.
+    pub function_jump: Option<FunctionJump>,
 }
 
 impl Element {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(solc_version: semver::Version, instruction: Instruction) -> Self {
+    pub fn new(solc_version: semver::Version, is_lenient: bool, instruction: Instruction) -> Self {
         Self {
             solc_version,
+            is_lenient,
             instruction,
             stack: Stack::new(),
+            function_jump: None,
         }
     }
 
@@ -163,6 +171,7 @@ where
                 ))
             }
             InstructionName::PUSH_Data => {
+                self.instruction.check_supported(self.is_lenient)?;
                 let value = self
                     .instruction
                     .value
@@ -375,15 +384,35 @@ where
                     self.stack.hash(),
                 )
             }
-            InstructionName::JUMP => {
-                let destination = self.stack.pop_tag()?;
-
-                crate::evmla::assembly::instruction::jump::unconditional(
+            InstructionName::JUMP => match self.function_jump {
+                Some(FunctionJump::Call {
+                    name,
+                    input_size,
+                    output_size,
+                    return_tag,
+                    return_stack_hash,
+                }) => crate::evmla::assembly::instruction::jump::call(
                     context,
-                    destination,
-                    self.stack.hash(),
-                )
-            }
+                    name.as_str(),
+                    self.stack.elements.len(),
+                    input_size,
+                    output_size,
+                    return_tag,
+                    return_stack_hash,
+                ),
+                Some(FunctionJump::Return { output_size }) => {
+                    crate::evmla::assembly::instruction::jump::r#return(context, output_size)
+                }
+                None => {
+                    let destination = self.stack.pop_tag()?;
+
+                    crate::evmla::assembly::instruction::jump::unconditional(
+                        context,
+                        destination,
+                        self.stack.hash(),
+                    )
+                }
+            },
             InstructionName::JUMPI => {
                 let destination = self.stack.pop_tag()?;
                 self.stack.pop()?;
@@ -874,6 +903,7 @@ where
                 )
             }
             InstructionName::CALLCODE => {
+                self.instruction.check_supported(self.is_lenient)?;
                 let mut _arguments = self.pop_arguments(context);
                 Ok(Some(context.field_const(0).as_basic_value_enum()))
             }
@@ -1018,12 +1048,17 @@ where
             InstructionName::BASEFEE => compiler_llvm_context::contract_context::basefee(context),
             InstructionName::MSIZE => compiler_llvm_context::contract_context::msize(context),
 
-            InstructionName::PC => Ok(Some(context.field_const(0).as_basic_value_enum())),
+            InstructionName::PC => {
+                self.instruction.check_supported(self.is_lenient)?;
+                Ok(Some(context.field_const(0).as_basic_value_enum()))
+            }
             InstructionName::EXTCODECOPY => {
+                self.instruction.check_supported(self.is_lenient)?;
                 let _arguments = self.pop_arguments_llvm(context);
                 Ok(None)
             }
             InstructionName::SELFDESTRUCT => {
+                self.instruction.check_supported(self.is_lenient)?;
                 let _arguments = self.pop_arguments_llvm(context);
                 Ok(None)
             }
@@ -1067,6 +1102,9 @@ impl std::fmt::Display for Element {
         if output_size != 0 {
             write!(f, " + {}", output)?;
         }
+        if let Some(ref location) = self.instruction.location {
+            write!(f, "    // {}", location)?;
+        }
         writeln!(f)?;
 
         Ok(())
@@ -3,16 +3,24 @@
 //!
 
 pub mod element;
+pub mod signature;
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use num::Zero;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
+use crate::evmla::ethereal_ir::function::visited_element::VisitedElement;
+use crate::evmla::ethereal_ir::function::Function;
 
+use self::element::function_jump::FunctionJump;
 use self::element::stack::Stack as ElementStack;
 use self::element::Element;
+use self::signature::Signature;
 
 ///
 /// The Ethereal IR block.
@@ -31,6 +39,10 @@ pub struct Block {
     pub initial_stack: ElementStack,
     /// The stack.
     pub stack: ElementStack,
+    /// The initial stack state hashes of the clones merged into this one.
+    pub aliases: Vec<md5::Digest>,
+    /// Whether the block has been truncated as partially unreachable.
+    pub is_truncated: bool,
 }
 
 impl Block {
@@ -44,6 +56,7 @@ impl Block {
     ///
     pub fn try_from_instructions(
         solc_version: semver::Version,
+        is_lenient: bool,
         code_type: compiler_llvm_context::CodeType,
         slice: &[Instruction],
     ) -> anyhow::Result<(Self, usize)> {
@@ -70,10 +83,13 @@ impl Block {
             predecessors: HashSet::with_capacity(Self::PREDECESSORS_HASHSET_DEFAULT_CAPACITY),
             initial_stack: ElementStack::new(),
             stack: ElementStack::new(),
+            aliases: Vec::new(),
+            is_truncated: false,
         };
 
         while cursor < slice.len() {
-            let element: Element = Element::new(solc_version.clone(), slice[cursor].to_owned());
+            let element: Element =
+                Element::new(solc_version.clone(), is_lenient, slice[cursor].to_owned());
             block.elements.push(element);
 
             match slice[cursor].name {
@@ -103,17 +119,123 @@ impl Block {
     pub fn insert_predecessor(&mut self, key: compiler_llvm_context::FunctionBlockKey) {
         self.predecessors.insert(key);
     }
+
+    ///
+    /// Returns the jump destination blocks with their initial stack state hashes, along with
+    /// the instructions jumping there, that is, `JUMP`, `JUMPI`, or the fall-through `Tag`.
+    ///
+    /// The recovered function calls are followed by the blocks the functions return to.
+    ///
+    /// Returns `None` if a jump destination is not a known tag.
+    ///
+    pub fn successors(&self) -> Option<Vec<(InstructionName, VisitedElement)>> {
+        let mut successors = Vec::new();
+        for element in self.elements.iter() {
+            let mut stack = element.stack.to_owned();
+            match element.function_jump {
+                Some(FunctionJump::Call {
+                    ref return_tag,
+                    return_stack_hash,
+                    ..
+                }) => {
+                    successors.push((
+                        InstructionName::JUMP,
+                        VisitedElement::new(
+                            Function::destination_key(self.key.code_type, return_tag.to_owned()),
+                            return_stack_hash,
+                        ),
+                    ));
+                    continue;
+                }
+                Some(FunctionJump::Return { .. }) => continue,
+                None => {}
+            }
+            let destination: num::BigUint = match element.instruction.name {
+                InstructionName::Tag => element.instruction.value.as_deref()?.parse().ok()?,
+                InstructionName::JUMP => stack.pop_tag().ok()?,
+                InstructionName::JUMPI => {
+                    let destination = stack.pop_tag().ok()?;
+                    stack.pop().ok()?;
+                    destination
+                }
+                _ => continue,
+            };
+
+            let block_key = Function::destination_key(self.key.code_type, destination);
+            successors.push((
+                element.instruction.name,
+                VisitedElement::new(block_key, stack.hash()),
+            ));
+        }
+        Some(successors)
+    }
+
+    ///
+    /// Returns the clone signature, resolving the jump destinations with the `representatives`,
+    /// which map the merged clones to the ones they have been merged into.
+    ///
+    /// Returns `None` if a jump destination is not a known tag, so the clone cannot be merged.
+    ///
+    pub fn signature(
+        &self,
+        representatives: &HashMap<VisitedElement, md5::Digest>,
+    ) -> Option<Signature> {
+        let successors = self
+            .successors()?
+            .into_iter()
+            .map(|(_name, successor)| match representatives.get(&successor) {
+                Some(hash) => VisitedElement::new(successor.block_key, *hash),
+                None => successor,
+            })
+            .collect();
+
+        Some(Signature::new(
+            self.initial_stack.elements.len(),
+            self.elements.len(),
+            self.elements.last().map(|element| element.instruction.name),
+            successors,
+        ))
+    }
+
+    ///
+    /// Merges the `other` clone, so its initial stack state also identifies this block.
+    ///
+    pub fn merge(&mut self, other: Self) {
+        self.aliases.push(other.initial_stack.hash());
+        self.aliases.extend(other.aliases);
+        self.predecessors.extend(other.predecessors);
+    }
 }
 
-impl<D> compiler_llvm_context::WriteLLVM<D> for Block
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Block
 where
     D: compiler_llvm_context::Dependency,
 {
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         context.set_code_type(self.key.code_type);
 
         for element in self.elements.into_iter() {
-            element.into_llvm(context)?;
+            if let Some(debug_info) = debug_info {
+                match (
+                    element.instruction.location.as_ref(),
+                    element.instruction.line_column,
+                ) {
+                    (Some(location), Some((line, column))) => {
+                        debug_info.set_location(context, Some(location.file.as_str()), line, column)
+                    }
+                    _ => debug_info.set_location(context, None, 0, 0),
+                }
+            }
+
+            let location = element.instruction.location.clone();
+            element.into_llvm(context).map_err(|error| match location {
+                Some(location) => anyhow::anyhow!("{} {}", location, error),
+                None => error,
+            })?;
         }
 
         Ok(())
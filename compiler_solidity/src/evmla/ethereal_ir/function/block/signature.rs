@@ -0,0 +1,43 @@
+//!
+//! The Ethereal IR block clone signature.
+//!
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::ethereal_ir::function::visited_element::VisitedElement;
+
+///
+/// The Ethereal IR block clone signature.
+///
+/// The clones of a block with equal signatures are translated into identical LLVM IR, since the
+/// translation only depends on the stack heights and the jump destinations.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    /// The initial stack height.
+    pub stack_height: usize,
+    /// The number of elements, which may differ if the clone has been truncated.
+    pub size: usize,
+    /// The last instruction, which may be replaced with `INVALID` if the clone has been truncated.
+    pub last: Option<InstructionName>,
+    /// The jump destination blocks with their initial stack state hashes.
+    pub successors: Vec<VisitedElement>,
+}
+
+impl Signature {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        stack_height: usize,
+        size: usize,
+        last: Option<InstructionName>,
+        successors: Vec<VisitedElement>,
+    ) -> Self {
+        Self {
+            stack_height,
+            size,
+            last,
+            successors,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//!
+//! The Ethereal IR control flow graph edge.
+//!
+
+use serde::Serialize;
+
+///
+/// The Ethereal IR control flow graph edge.
+///
+#[derive(Debug, Serialize, Clone)]
+pub struct Edge {
+    /// The source node identifier.
+    pub from: String,
+    /// The destination node identifier.
+    pub to: String,
+    /// The edge kind, that is, `jump`, `jumpi`, `fallthrough`, or `call`.
+    pub kind: &'static str,
+}
+
+impl Edge {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(from: String, to: String, kind: &'static str) -> Self {
+        Self { from, to, kind }
+    }
+}
@@ -0,0 +1,43 @@
+//!
+//! The Ethereal IR control flow graph output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The Ethereal IR control flow graph output format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The Graphviz DOT language.
+    Dot,
+    /// The JSON object with the node and edge arrays.
+    JSON,
+}
+
+impl Format {
+    ///
+    /// Returns the file extension of the format.
+    ///
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::JSON => "json",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "dot" => Ok(Self::Dot),
+            "json" => Ok(Self::JSON),
+            value => anyhow::bail!(
+                "Invalid Ethereal IR CFG format `{}`. Available formats: dot, json",
+                value
+            ),
+        }
+    }
+}
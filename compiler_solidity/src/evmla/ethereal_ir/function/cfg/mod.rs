@@ -0,0 +1,197 @@
+//!
+//! The Ethereal IR control flow graph.
+//!
+
+pub mod edge;
+pub mod format;
+pub mod node;
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::ethereal_ir::function::block::element::function_jump::FunctionJump;
+use crate::evmla::ethereal_ir::function::r#type::Type;
+use crate::evmla::ethereal_ir::function::visited_element::VisitedElement;
+use crate::evmla::ethereal_ir::function::Function;
+use crate::evmla::ethereal_ir::EtherealIR;
+
+use self::edge::Edge;
+use self::format::Format;
+use self::node::Node;
+
+///
+/// The Ethereal IR control flow graph, where each block clone is a separate node.
+///
+#[derive(Debug, Serialize, Clone)]
+pub struct CFG {
+    /// The block clone nodes.
+    pub nodes: Vec<Node>,
+    /// The jump and fall-through edges.
+    pub edges: Vec<Edge>,
+}
+
+impl CFG {
+    ///
+    /// Builds the control flow graph of the entry function and the recovered subroutines, where
+    /// the recovered calls are connected to the subroutine entry blocks.
+    ///
+    /// The edges to the blocks which have not been reached by the stack analysis are omitted.
+    ///
+    pub fn new(ethereal_ir: &EtherealIR) -> Self {
+        let functions: Vec<&Function> = std::iter::once(&ethereal_ir.function)
+            .chain(ethereal_ir.functions.values())
+            .collect();
+
+        let mut nodes = Vec::new();
+        let mut identifiers = HashMap::new();
+        for function in functions.iter() {
+            for (key, blocks) in function.blocks.iter() {
+                for (index, block) in blocks.iter().enumerate() {
+                    let id = Self::node_id(function.name.as_str(), key, index);
+                    for hash in std::iter::once(block.initial_stack.hash())
+                        .chain(block.aliases.iter().copied())
+                    {
+                        identifiers.insert(
+                            (
+                                function.name.as_str(),
+                                VisitedElement::new(key.to_owned(), hash),
+                            ),
+                            id.clone(),
+                        );
+                    }
+
+                    let code_type = match key.code_type {
+                        compiler_llvm_context::CodeType::Deploy => "deploy",
+                        compiler_llvm_context::CodeType::Runtime => "runtime",
+                    };
+                    nodes.push(Node::new(
+                        id,
+                        function.name.clone(),
+                        code_type,
+                        key.tag.to_string(),
+                        index,
+                        block.initial_stack.to_string(),
+                        block.is_truncated,
+                    ));
+                }
+            }
+        }
+
+        let mut edges = Vec::new();
+        for function in functions.iter() {
+            for (key, blocks) in function.blocks.iter() {
+                for (index, block) in blocks.iter().enumerate() {
+                    let from = Self::node_id(function.name.as_str(), key, index);
+                    for (name, successor) in block.successors().unwrap_or_default().into_iter() {
+                        let to = match identifiers.get(&(function.name.as_str(), successor)) {
+                            Some(to) => to.to_owned(),
+                            None => continue,
+                        };
+                        let kind = match name {
+                            InstructionName::JUMP => "jump",
+                            InstructionName::JUMPI => "jumpi",
+                            _ => "fallthrough",
+                        };
+                        edges.push(Edge::new(from.clone(), to, kind));
+                    }
+
+                    for element in block.elements.iter() {
+                        if let Some(FunctionJump::Call { ref name, .. }) = element.function_jump {
+                            let entry_key = match ethereal_ir.functions.get(name) {
+                                Some(Function {
+                                    r#type: Type::Subroutine { ref entry_key, .. },
+                                    ..
+                                }) => entry_key,
+                                _ => continue,
+                            };
+                            edges.push(Edge::new(
+                                from.clone(),
+                                Self::node_id(name.as_str(), entry_key, 0),
+                                "call",
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { nodes, edges }
+    }
+
+    ///
+    /// Serializes the graph in the specified `format`, naming it after the contract `path`.
+    ///
+    pub fn render(&self, format: Format, path: &str) -> String {
+        match format {
+            Format::Dot => self.to_dot(path),
+            Format::JSON => serde_json::to_string_pretty(self).expect("Always valid"),
+        }
+    }
+
+    ///
+    /// Serializes the graph in the Graphviz DOT language.
+    ///
+    /// The functions are clustered, the truncated blocks are colored red, the conditional and
+    /// fall-through edges are dashed and dotted respectively, and the call edges are blue.
+    ///
+    fn to_dot(&self, path: &str) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n", Self::escape(path));
+        dot.push_str("    node [shape=box fontname=\"monospace\"];\n");
+        let mut functions: Vec<&str> = Vec::new();
+        for node in self.nodes.iter() {
+            if !functions.contains(&node.function.as_str()) {
+                functions.push(node.function.as_str());
+            }
+        }
+        for function in functions.into_iter() {
+            dot.push_str(format!("    subgraph \"cluster_{}\" {{\n", function).as_str());
+            dot.push_str(format!("        label=\"{}\";\n", function).as_str());
+            for node in self.nodes.iter().filter(|node| node.function == function) {
+                dot.push_str(
+                    format!(
+                        "        \"{}\" [label=\"{}\\n{}\"{}];\n",
+                        node.id,
+                        node.id,
+                        Self::escape(node.initial_stack.as_str()),
+                        if node.is_truncated { " color=red" } else { "" }
+                    )
+                    .as_str(),
+                );
+            }
+            dot.push_str("    }\n");
+        }
+        for edge in self.edges.iter() {
+            let style = match edge.kind {
+                "jumpi" => " [style=dashed]",
+                "fallthrough" => " [style=dotted]",
+                "call" => " [style=bold color=blue]",
+                _ => "",
+            };
+            dot.push_str(format!("    \"{}\" -> \"{}\"{};\n", edge.from, edge.to, style).as_str());
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    ///
+    /// Returns the identifier of the block clone node, which is unique across the functions.
+    ///
+    /// The entry block of a subroutine is always the first clone of its tag.
+    ///
+    fn node_id(
+        function: &str,
+        key: &compiler_llvm_context::FunctionBlockKey,
+        index: usize,
+    ) -> String {
+        format!("{}/block_{}/{}", function, key, index)
+    }
+
+    ///
+    /// Escapes the DOT string literal.
+    ///
+    fn escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! The Ethereal IR control flow graph node.
+//!
+
+use serde::Serialize;
+
+///
+/// The Ethereal IR control flow graph node, which is a block clone.
+///
+#[derive(Debug, Serialize, Clone)]
+pub struct Node {
+    /// The node identifier, that is, the LLVM IR function and block names.
+    pub id: String,
+    /// The LLVM IR function name.
+    pub function: String,
+    /// The code type, that is, `deploy` or `runtime`.
+    pub code_type: &'static str,
+    /// The block tag.
+    pub tag: String,
+    /// The clone index among the clones of the block.
+    pub clone: usize,
+    /// The initial stack state.
+    pub initial_stack: String,
+    /// Whether the block has been truncated as partially unreachable.
+    pub is_truncated: bool,
+}
+
+impl Node {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        id: String,
+        function: String,
+        code_type: &'static str,
+        tag: String,
+        clone: usize,
+        initial_stack: String,
+        is_truncated: bool,
+    ) -> Self {
+        Self {
+            id,
+            function,
+            code_type,
+            tag,
+            clone,
+            initial_stack,
+            is_truncated,
+        }
+    }
+}
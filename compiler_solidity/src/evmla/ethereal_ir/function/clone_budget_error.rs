@@ -0,0 +1,33 @@
+//!
+//! The Ethereal IR block clone budget error.
+//!
+
+///
+/// The Ethereal IR block clone budget error.
+///
+/// Distinguished from the errors of the subroutine analysis, which only make the subroutine
+/// inlined, since exceeding the budget must fail the whole translation.
+///
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+#[error(
+    "The Ethereal IR block clone budget of {clone_budget} is exceeded. The most cloned blocks: {}",
+    .hottest_tags.join(", ")
+)]
+pub struct CloneBudgetError {
+    /// The maximum number of block clones.
+    pub clone_budget: usize,
+    /// The most cloned blocks with their numbers of clones.
+    pub hottest_tags: Vec<String>,
+}
+
+impl CloneBudgetError {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(clone_budget: usize, hottest_tags: Vec<String>) -> Self {
+        Self {
+            clone_budget,
+            hottest_tags,
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//!
+//! The Ethereal IR stack constant folding.
+//!
+
+use num::BigInt;
+use num::BigUint;
+use num::One;
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::ethereal_ir::function::block::element::stack::element::Element;
+
+/// The EVM word bit length.
+const BIT_LENGTH: usize = 256;
+
+/// The EVM word byte length.
+const BYTE_LENGTH: usize = BIT_LENGTH / 8;
+
+///
+/// Folds the instruction `name` applied to the `operands`, which are ordered from the top of the
+/// stack, that is, in the EVM argument order.
+///
+/// The constants are computed with the modular 256-bit EVM semantics. The tags are propagated
+/// through the bitwise instructions and as the shifted values, so the computed jump targets can
+/// be resolved. Any other operand combination yields an unknown runtime value.
+///
+pub fn fold(name: &InstructionName, operands: &[&Element]) -> Element {
+    let mut is_tag = false;
+    let mut values = Vec::with_capacity(operands.len());
+    for (index, operand) in operands.iter().enumerate() {
+        match operand {
+            Element::Constant(value) => values.push(truncate(value.to_owned())),
+            Element::Tag(value) if is_tag_propagated(name, index) => {
+                is_tag = true;
+                values.push(truncate(value.to_owned()));
+            }
+            _ => return Element::Value,
+        }
+    }
+
+    let result = match (name, values.as_slice()) {
+        (InstructionName::ADD, [operand_1, operand_2]) => truncate(operand_1 + operand_2),
+        (InstructionName::SUB, [operand_1, operand_2]) => {
+            truncate(operand_1 + modulus() - operand_2)
+        }
+        (InstructionName::MUL, [operand_1, operand_2]) => truncate(operand_1 * operand_2),
+        (InstructionName::DIV, [_operand_1, operand_2]) if operand_2.is_zero() => BigUint::zero(),
+        (InstructionName::DIV, [operand_1, operand_2]) => operand_1 / operand_2,
+        (InstructionName::MOD, [_operand_1, operand_2]) if operand_2.is_zero() => BigUint::zero(),
+        (InstructionName::MOD, [operand_1, operand_2]) => operand_1 % operand_2,
+        (InstructionName::EXP, [base, exponent]) => base.modpow(exponent, &modulus()),
+        (InstructionName::SIGNEXTEND, [byte_index, value]) => sign_extend(byte_index, value),
+
+        (InstructionName::LT, [operand_1, operand_2]) => boolean(operand_1 < operand_2),
+        (InstructionName::GT, [operand_1, operand_2]) => boolean(operand_1 > operand_2),
+        (InstructionName::SLT, [operand_1, operand_2]) => {
+            boolean(to_signed(operand_1) < to_signed(operand_2))
+        }
+        (InstructionName::SGT, [operand_1, operand_2]) => {
+            boolean(to_signed(operand_1) > to_signed(operand_2))
+        }
+        (InstructionName::EQ, [operand_1, operand_2]) => boolean(operand_1 == operand_2),
+        (InstructionName::ISZERO, [operand]) => boolean(operand.is_zero()),
+
+        (InstructionName::AND, [operand_1, operand_2]) => operand_1 & operand_2,
+        (InstructionName::OR, [operand_1, operand_2]) => operand_1 | operand_2,
+        (InstructionName::XOR, [operand_1, operand_2]) => operand_1 ^ operand_2,
+        (InstructionName::NOT, [operand]) => max() - operand,
+        (InstructionName::BYTE, [byte_index, value]) => byte(byte_index, value),
+        (InstructionName::SHL, [offset, value]) => match shift_offset(offset) {
+            Some(offset) => truncate(value << offset),
+            None => BigUint::zero(),
+        },
+        (InstructionName::SHR, [offset, value]) => match shift_offset(offset) {
+            Some(offset) => value >> offset,
+            None => BigUint::zero(),
+        },
+        (InstructionName::SAR, [offset, value]) => shift_right_arithmetic(offset, value),
+
+        _ => return Element::Value,
+    };
+
+    if is_tag {
+        Element::Tag(result)
+    } else {
+        Element::Constant(result)
+    }
+}
+
+///
+/// Whether the tag operand at `index` of the instruction `name` keeps the result a tag.
+///
+fn is_tag_propagated(name: &InstructionName, index: usize) -> bool {
+    match name {
+        InstructionName::AND | InstructionName::OR | InstructionName::XOR => true,
+        InstructionName::SHL | InstructionName::SHR => index == 1,
+        _ => false,
+    }
+}
+
+///
+/// Returns the EVM word modulus, that is, `2^256`.
+///
+fn modulus() -> BigUint {
+    BigUint::one() << BIT_LENGTH
+}
+
+///
+/// Returns the EVM word maximum value, that is, `2^256 - 1`.
+///
+fn max() -> BigUint {
+    modulus() - BigUint::one()
+}
+
+///
+/// Truncates the value to the EVM word.
+///
+fn truncate(value: BigUint) -> BigUint {
+    value % modulus()
+}
+
+///
+/// Converts the boolean to the EVM word.
+///
+fn boolean(value: bool) -> BigUint {
+    if value {
+        BigUint::one()
+    } else {
+        BigUint::zero()
+    }
+}
+
+///
+/// Interprets the EVM word as a two's complement signed integer.
+///
+fn to_signed(value: &BigUint) -> BigInt {
+    if value.bit((BIT_LENGTH - 1) as u64) {
+        BigInt::from(value.to_owned()) - BigInt::from(modulus())
+    } else {
+        BigInt::from(value.to_owned())
+    }
+}
+
+///
+/// Returns the shift offset, if it is less than the EVM word bit length.
+///
+fn shift_offset(offset: &BigUint) -> Option<usize> {
+    offset.to_usize().filter(|offset| *offset < BIT_LENGTH)
+}
+
+///
+/// The `SAR` instruction, which fills the vacated bits with the sign bit.
+///
+fn shift_right_arithmetic(offset: &BigUint, value: &BigUint) -> BigUint {
+    let is_negative = value.bit((BIT_LENGTH - 1) as u64);
+    match shift_offset(offset) {
+        Some(offset) if is_negative => (value >> offset) | (max() - (max() >> offset)),
+        Some(offset) => value >> offset,
+        None if is_negative => max(),
+        None => BigUint::zero(),
+    }
+}
+
+///
+/// The `BYTE` instruction, which takes the byte at the big-endian `byte_index`.
+///
+fn byte(byte_index: &BigUint, value: &BigUint) -> BigUint {
+    match byte_index.to_usize().filter(|index| *index < BYTE_LENGTH) {
+        Some(index) => (value >> (8 * (BYTE_LENGTH - 1 - index))) & BigUint::from(0xffu8),
+        None => BigUint::zero(),
+    }
+}
+
+///
+/// The `SIGNEXTEND` instruction, which extends the sign bit of the byte at the little-endian
+/// `byte_index`.
+///
+fn sign_extend(byte_index: &BigUint, value: &BigUint) -> BigUint {
+    let index = match byte_index
+        .to_usize()
+        .filter(|index| *index < BYTE_LENGTH - 1)
+    {
+        Some(index) => index,
+        None => return value.to_owned(),
+    };
+
+    let sign_bit = 8 * index + 7;
+    let mask = (BigUint::one() << (sign_bit + 1)) - BigUint::one();
+    if value.bit(sign_bit as u64) {
+        value | (max() - mask)
+    } else {
+        value & mask
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use rand::Rng;
+
+    use crate::evmla::assembly::instruction::name::Name as InstructionName;
+    use crate::evmla::ethereal_ir::function::block::element::stack::element::Element;
+
+    /// The number of random cases per instruction.
+    const CASES: usize = 2048;
+
+    fn random_word(rng: &mut impl Rng) -> primitive_types::U256 {
+        match rng.gen_range(0..6) {
+            0 => primitive_types::U256::from(rng.gen_range(0u64..300)),
+            1 => primitive_types::U256::MAX - rng.gen_range(0u64..300),
+            2 => primitive_types::U256::one() << rng.gen_range(0..256),
+            3 => primitive_types::U256::from(rng.gen::<u64>()),
+            _ => primitive_types::U256(rng.gen::<[u64; 4]>()),
+        }
+    }
+
+    fn to_biguint(value: primitive_types::U256) -> BigUint {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        BigUint::from_bytes_be(&bytes)
+    }
+
+    fn is_negative(value: primitive_types::U256) -> bool {
+        value.bit(255)
+    }
+
+    fn reference(
+        name: &InstructionName,
+        operands: &[primitive_types::U256],
+    ) -> primitive_types::U256 {
+        let zero = primitive_types::U256::zero();
+        let boolean = |value: bool| primitive_types::U256::from(value as u8);
+        match (name, operands) {
+            (InstructionName::ADD, [a, b]) => a.overflowing_add(*b).0,
+            (InstructionName::SUB, [a, b]) => a.overflowing_sub(*b).0,
+            (InstructionName::MUL, [a, b]) => a.overflowing_mul(*b).0,
+            (InstructionName::DIV, [a, b]) => a.checked_div(*b).unwrap_or(zero),
+            (InstructionName::MOD, [a, b]) => a.checked_rem(*b).unwrap_or(zero),
+            (InstructionName::EXP, [a, b]) => a.overflowing_pow(*b).0,
+            (InstructionName::SIGNEXTEND, [b, x]) => {
+                if *b >= primitive_types::U256::from(31) {
+                    return *x;
+                }
+                let bit = b.low_u64() as usize * 8 + 7;
+                let mask = (primitive_types::U256::one() << (bit + 1)) - 1;
+                if x.bit(bit) {
+                    *x | !mask
+                } else {
+                    *x & mask
+                }
+            }
+            (InstructionName::LT, [a, b]) => boolean(a < b),
+            (InstructionName::GT, [a, b]) => boolean(a > b),
+            (InstructionName::SLT, [a, b]) => boolean(match (is_negative(*a), is_negative(*b)) {
+                (true, false) => true,
+                (false, true) => false,
+                _ => a < b,
+            }),
+            (InstructionName::SGT, [a, b]) => boolean(match (is_negative(*a), is_negative(*b)) {
+                (true, false) => false,
+                (false, true) => true,
+                _ => a > b,
+            }),
+            (InstructionName::EQ, [a, b]) => boolean(a == b),
+            (InstructionName::ISZERO, [a]) => boolean(a.is_zero()),
+            (InstructionName::AND, [a, b]) => *a & *b,
+            (InstructionName::OR, [a, b]) => *a | *b,
+            (InstructionName::XOR, [a, b]) => *a ^ *b,
+            (InstructionName::NOT, [a]) => !*a,
+            (InstructionName::BYTE, [i, x]) => {
+                if *i >= primitive_types::U256::from(32) {
+                    zero
+                } else {
+                    primitive_types::U256::from(x.byte(31 - i.low_u64() as usize))
+                }
+            }
+            (InstructionName::SHL, [shift, value]) => {
+                if *shift >= primitive_types::U256::from(256) {
+                    zero
+                } else {
+                    *value << shift.low_u64() as usize
+                }
+            }
+            (InstructionName::SHR, [shift, value]) => {
+                if *shift >= primitive_types::U256::from(256) {
+                    zero
+                } else {
+                    *value >> shift.low_u64() as usize
+                }
+            }
+            (InstructionName::SAR, [shift, value]) => {
+                if is_negative(*value) {
+                    !reference(&InstructionName::SHR, &[*shift, !*value])
+                } else {
+                    reference(&InstructionName::SHR, operands)
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn check(name: InstructionName, input_size: usize) {
+        let mut rng = rand::thread_rng();
+        for _ in 0..CASES {
+            let operands: Vec<primitive_types::U256> =
+                (0..input_size).map(|_| random_word(&mut rng)).collect();
+            let elements: Vec<Element> = operands
+                .iter()
+                .map(|operand| Element::Constant(to_biguint(*operand)))
+                .collect();
+            let elements: Vec<&Element> = elements.iter().collect();
+
+            let expected = Element::Constant(to_biguint(reference(&name, operands.as_slice())));
+            let result = super::fold(&name, elements.as_slice());
+            assert_eq!(
+                result, expected,
+                "{:?} of {:?} is {}, expected {}",
+                name, operands, result, expected
+            );
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        check(InstructionName::ADD, 2);
+        check(InstructionName::SUB, 2);
+        check(InstructionName::MUL, 2);
+        check(InstructionName::DIV, 2);
+        check(InstructionName::MOD, 2);
+        check(InstructionName::EXP, 2);
+        check(InstructionName::SIGNEXTEND, 2);
+    }
+
+    #[test]
+    fn comparison() {
+        check(InstructionName::LT, 2);
+        check(InstructionName::GT, 2);
+        check(InstructionName::SLT, 2);
+        check(InstructionName::SGT, 2);
+        check(InstructionName::EQ, 2);
+        check(InstructionName::ISZERO, 1);
+    }
+
+    #[test]
+    fn bitwise() {
+        check(InstructionName::AND, 2);
+        check(InstructionName::OR, 2);
+        check(InstructionName::XOR, 2);
+        check(InstructionName::NOT, 1);
+        check(InstructionName::BYTE, 2);
+        check(InstructionName::SHL, 2);
+        check(InstructionName::SHR, 2);
+        check(InstructionName::SAR, 2);
+    }
+
+    #[test]
+    fn tags() {
+        let tag = Element::Tag(BigUint::from(42u64));
+        let offset = Element::Constant(BigUint::from(224u64));
+        let mask = Element::Constant(BigUint::from(u32::MAX));
+
+        assert_eq!(
+            super::fold(&InstructionName::SHL, &[&offset, &tag]),
+            Element::Tag(BigUint::from(42u64) << 224)
+        );
+        assert_eq!(
+            super::fold(&InstructionName::AND, &[&mask, &tag]),
+            Element::Tag(BigUint::from(42u64))
+        );
+        assert_eq!(
+            super::fold(&InstructionName::AND, &[&mask, &mask]),
+            Element::Constant(BigUint::from(u32::MAX))
+        );
+        assert_eq!(
+            super::fold(&InstructionName::SHL, &[&tag, &offset]),
+            Element::Value
+        );
+        assert_eq!(
+            super::fold(&InstructionName::ADD, &[&tag, &mask]),
+            Element::Value
+        );
+    }
+}
@@ -3,7 +3,15 @@
 //!
 
 pub mod block;
+pub mod cfg;
+pub mod clone_budget_error;
+pub mod constant_folding;
 pub mod queue_element;
+pub mod recursive_call;
+pub mod stats;
+pub mod subroutines;
+pub mod truncation;
+pub mod r#type;
 pub mod visited_element;
 
 use std::collections::BTreeMap;
@@ -13,23 +21,28 @@ use std::ops::BitAnd;
 
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
-use num::CheckedAdd;
-use num::CheckedMul;
-use num::CheckedSub;
 use num::Num;
-use num::ToPrimitive;
 use num::Zero;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
 use crate::evmla::ethereal_ir::function::block::element::stack::element::Element;
 use crate::evmla::ethereal_ir::function::block::element::stack::Stack;
 use crate::evmla::ethereal_ir::EtherealIR;
 
+use self::block::element::function_jump::FunctionJump;
 use self::block::element::stack::element::Element as StackElement;
 use self::block::element::Element as BlockElement;
 use self::block::Block;
+use self::clone_budget_error::CloneBudgetError;
 use self::queue_element::QueueElement;
+use self::r#type::Type;
+use self::recursive_call::RecursiveCall;
+use self::stats::Stats;
+use self::subroutines::Subroutines;
+use self::truncation::Truncation;
 use self::visited_element::VisitedElement;
 
 ///
@@ -39,25 +52,51 @@ use self::visited_element::VisitedElement;
 pub struct Function {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
+    /// The LLVM function name.
+    pub name: String,
+    /// The function type.
+    pub r#type: Type,
     /// The separately labelled blocks.
     pub blocks: BTreeMap<compiler_llvm_context::FunctionBlockKey, Vec<Block>>,
     /// The function stack size.
     pub stack_size: usize,
+    /// The maximum number of the function block clones, if limited.
+    pub clone_budget: Option<usize>,
+    /// The number of block clones created.
+    pub clones: usize,
+    /// The number of block clones merged into identical ones.
+    pub merged_clones: usize,
+    /// The blocks truncated as unreachable, one per block tag.
+    pub truncations: Vec<Truncation>,
 }
 
 impl Function {
+    /// The EVM stack depth limit, which is only exceeded if a recursive subroutine is inlined.
+    pub const STACK_DEPTH_LIMIT: usize = 1024;
+
     ///
     /// A shortcut constructor.
     ///
+    /// The subroutines recognized on the way are recovered into separate functions, which are
+    /// collected into `subroutines`. If the subroutines clone budget is set, the construction
+    /// fails as soon as more block clones are created.
+    ///
     pub fn new(
         solc_version: semver::Version,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
         visited: &mut HashSet<VisitedElement>,
+        subroutines: &mut Subroutines,
     ) -> anyhow::Result<Self> {
         let mut function = Self {
             solc_version,
+            name: EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME.to_owned(),
+            r#type: Type::Main,
             blocks: BTreeMap::new(),
             stack_size: 0,
+            clone_budget: subroutines.clone_budget,
+            clones: 0,
+            merged_clones: 0,
+            truncations: Vec::new(),
         };
         function.consume_block(
             blocks,
@@ -70,6 +109,7 @@ impl Function {
                 None,
                 Stack::new(),
             ),
+            subroutines,
         )?;
         function.consume_block(
             blocks,
@@ -82,10 +122,173 @@ impl Function {
                 None,
                 Stack::new(),
             ),
+            subroutines,
         )?;
         Ok(function.finalize())
     }
 
+    ///
+    /// Recovers the subroutine starting at `entry_key` and taking `input_size` arguments.
+    ///
+    /// The subroutine is entered with its return address below the arguments. It is proven to be
+    /// a function if it only jumps to the return address, having the same number of return values
+    /// above, and never touches the stack below the return address.
+    ///
+    /// The blocks the recursive calls return to are analyzed after the number of return values
+    /// is known from the other returns.
+    ///
+    fn new_subroutine(
+        solc_version: semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+        subroutines: &mut Subroutines,
+    ) -> anyhow::Result<Self> {
+        let name = Self::subroutine_name(&entry_key, input_size);
+        let mut function = Self {
+            solc_version,
+            name: name.clone(),
+            r#type: Type::Subroutine {
+                entry_key: entry_key.clone(),
+                input_size,
+                output_size: None,
+            },
+            blocks: BTreeMap::new(),
+            stack_size: 0,
+            clone_budget: subroutines.clone_budget,
+            clones: 0,
+            merged_clones: 0,
+            truncations: Vec::new(),
+        };
+
+        let mut initial_stack = Stack::new();
+        initial_stack.push(StackElement::ReturnAddress);
+        for _ in 0..input_size {
+            initial_stack.push(StackElement::Value);
+        }
+        let mut visited = HashSet::with_capacity(blocks.len());
+        function.consume_block(
+            blocks,
+            &mut visited,
+            QueueElement::new(entry_key, None, initial_stack),
+            subroutines,
+        )?;
+
+        loop {
+            let output_size = match function.r#type {
+                Type::Subroutine {
+                    output_size: Some(output_size),
+                    ..
+                } => output_size,
+                _ => anyhow::bail!("The subroutine `{}` never returns", name),
+            };
+
+            let (recursive_calls, other_calls): (Vec<RecursiveCall>, Vec<RecursiveCall>) =
+                subroutines
+                    .recursive_calls
+                    .drain(..)
+                    .partition(|call| call.function_name == name);
+            subroutines.recursive_calls = other_calls;
+            if recursive_calls.is_empty() {
+                break;
+            }
+
+            for call in recursive_calls.into_iter() {
+                let mut return_stack = call.return_stack;
+                for _ in 0..output_size {
+                    return_stack.push(StackElement::Value);
+                }
+
+                let caller = function
+                    .blocks
+                    .get_mut(&call.caller.block_key)
+                    .and_then(|blocks| {
+                        blocks
+                            .iter_mut()
+                            .find(|block| block.initial_stack.hash() == call.caller.stack_hash)
+                    })
+                    .and_then(|block| block.elements.last_mut())
+                    .expect("Always exists");
+                caller.function_jump = Some(FunctionJump::call(
+                    name.clone(),
+                    call.input_size,
+                    output_size,
+                    call.return_tag.clone(),
+                    return_stack.hash(),
+                ));
+
+                function.consume_block(
+                    blocks,
+                    &mut visited,
+                    QueueElement::new(
+                        Self::destination_key(call.caller.block_key.code_type, call.return_tag),
+                        Some(call.caller.block_key),
+                        return_stack,
+                    ),
+                    subroutines,
+                )?;
+            }
+        }
+        Ok(function.finalize())
+    }
+
+    ///
+    /// Returns the name and number of return values of the recovered subroutine, recovering it
+    /// on the first call.
+    ///
+    /// Returns `None` if the subroutine is not proven to be a function, so it must be inlined.
+    ///
+    /// Returns an error if the clone budget is exceeded while recovering the subroutine.
+    ///
+    fn recover_subroutine(
+        solc_version: &semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+        subroutines: &mut Subroutines,
+    ) -> anyhow::Result<Option<(String, usize)>> {
+        let signature_key = (entry_key.clone(), input_size);
+        if let Some(signature) = subroutines.signatures.get(&signature_key) {
+            return Ok(signature.to_owned());
+        }
+        subroutines.signatures.insert(signature_key.clone(), None);
+
+        let name = Self::subroutine_name(&entry_key, input_size);
+        subroutines.in_progress.push(signature_key.clone());
+        let function = Self::new_subroutine(
+            solc_version.to_owned(),
+            blocks,
+            entry_key,
+            input_size,
+            subroutines,
+        );
+        subroutines.in_progress.pop();
+        subroutines
+            .recursive_calls
+            .retain(|call| call.function_name != name);
+        let function = match function {
+            Ok(function) => function,
+            Err(error) if error.is::<CloneBudgetError>() => return Err(error),
+            Err(_) => return Ok(None),
+        };
+        let output_size = match function.r#type {
+            Type::Subroutine {
+                output_size: Some(output_size),
+                ..
+            } => output_size,
+            _ => return Ok(None),
+        };
+
+        let signature = (function.name.clone(), output_size);
+        subroutines
+            .functions
+            .insert(function.name.clone(), function);
+        subroutines
+            .signatures
+            .insert(signature_key, Some(signature.clone()));
+        Ok(Some(signature))
+    }
+
     ///
     /// Consumes the entry or a conditional block attached to another one.
     ///
@@ -94,8 +297,12 @@ impl Function {
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
         visited: &mut HashSet<VisitedElement>,
         mut queue_element: QueueElement,
+        subroutines: &mut Subroutines,
     ) -> anyhow::Result<()> {
         let version = self.solc_version.to_owned();
+        let is_subroutine = matches!(self.r#type, Type::Subroutine { .. });
+        let mut output_sizes = Vec::new();
+        let mut truncation = None;
 
         let mut queue = vec![];
 
@@ -106,6 +313,14 @@ impl Function {
         }
         visited.insert(visited_element);
 
+        if queue_element.stack.elements.len() > Self::STACK_DEPTH_LIMIT {
+            anyhow::bail!(
+                "The block {} stack depth exceeds the limit of {}, which is caused by inlining a recursive subroutine",
+                queue_element.block_key,
+                Self::STACK_DEPTH_LIMIT
+            );
+        }
+
         let mut block = blocks
             .get(&queue_element.block_key)
             .cloned()
@@ -113,40 +328,250 @@ impl Function {
                 anyhow::anyhow!("Undeclared destination block {}", queue_element.block_key)
             })?;
         block.initial_stack = queue_element.stack.clone();
-        let block = self.insert_block(block);
+        let block = self.insert_block(block)?;
         block.stack = block.initial_stack.clone();
         if let Some(predecessor) = queue_element.predecessor.take() {
             block.insert_predecessor(predecessor);
         }
 
+        let caller =
+            VisitedElement::new(queue_element.block_key.clone(), block.initial_stack.hash());
         let mut block_size = 0;
         for block_element in block.elements.iter_mut() {
             block_size += 1;
 
-            if Self::handle_instruction(
-                block.key.code_type,
+            let result = match Self::handle_function_jump(
+                &caller,
                 &mut block.stack,
                 block_element,
                 &version,
+                blocks,
+                subroutines,
                 &mut queue,
-                &mut queue_element,
-            )
-            .is_err()
-            {
+            ) {
+                Ok(Some(FunctionJump::Return { output_size })) => {
+                    output_sizes.push(output_size);
+                    Ok(())
+                }
+                Ok(Some(FunctionJump::Call { .. })) => Ok(()),
+                Ok(None) => Self::handle_instruction(
+                    block.key.code_type,
+                    &mut block.stack,
+                    block_element,
+                    &version,
+                    &mut queue,
+                    &mut queue_element,
+                ),
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                if is_subroutine || error.is::<CloneBudgetError>() {
+                    return Err(error);
+                }
+
+                truncation = Some(Truncation::new(
+                    block.key.clone(),
+                    block_size - 1,
+                    block_element.instruction.name,
+                    error.to_string(),
+                    block_element.instruction.location.clone(),
+                ));
                 block_element.stack = block.stack.clone();
                 block_element.instruction = Instruction::invalid();
+                block.is_truncated = true;
                 break;
             }
         }
         block.elements.truncate(block_size);
 
+        if let Some(truncation) = truncation {
+            if !self.truncations.iter().any(|existing| {
+                existing.block_key == truncation.block_key && existing.index == truncation.index
+            }) {
+                self.truncations.push(truncation);
+            }
+        }
+
+        if let Type::Subroutine {
+            output_size: ref mut function_output_size,
+            ..
+        } = self.r#type
+        {
+            for output_size in output_sizes.into_iter() {
+                match function_output_size {
+                    Some(function_output_size) if *function_output_size != output_size => {
+                        anyhow::bail!(
+                            "The subroutine `{}` returns both {} and {} values",
+                            self.name,
+                            function_output_size,
+                            output_size
+                        );
+                    }
+                    Some(_) => {}
+                    None => *function_output_size = Some(output_size),
+                }
+            }
+        }
+
         for element in queue.into_iter() {
-            self.consume_block(blocks, visited, element)?;
+            self.consume_block(blocks, visited, element, subroutines)?;
         }
 
         Ok(())
     }
 
+    ///
+    /// Processes a `JUMP` instruction as a recovered function call or return, if possible.
+    ///
+    /// A jump to the return address is a return, and a jump marked as `[in]` is a call if there
+    /// is a return tag below the destination and the subroutine can be recovered.
+    ///
+    /// A call of the subroutine being analyzed is recorded as a recursive call, and its number of
+    /// return values is set after the subroutine is proven to return. A call of another subroutine
+    /// being analyzed, that is, a mutual recursion, fails the analysis of the current one.
+    ///
+    /// Returns `None` if the instruction must be processed as an ordinary jump, which falls back
+    /// to inlining the subroutine.
+    ///
+    fn handle_function_jump(
+        caller: &VisitedElement,
+        block_stack: &mut Stack,
+        block_element: &mut BlockElement,
+        version: &semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        subroutines: &mut Subroutines,
+        queue: &mut Vec<QueueElement>,
+    ) -> anyhow::Result<Option<FunctionJump>> {
+        if block_element.instruction.name != InstructionName::JUMP {
+            return Ok(None);
+        }
+
+        let destination = match block_stack.elements.last() {
+            Some(StackElement::ReturnAddress) => {
+                block_element.stack = block_stack.clone();
+                block_stack.pop()?;
+                if block_stack.elements.iter().any(|element| {
+                    matches!(element, StackElement::Tag(_) | StackElement::ReturnAddress)
+                }) {
+                    anyhow::bail!("The subroutine return values must be runtime values");
+                }
+
+                let function_jump = FunctionJump::r#return(block_stack.elements.len());
+                block_element.function_jump = Some(function_jump.clone());
+                return Ok(Some(function_jump));
+            }
+            Some(StackElement::Tag(destination))
+                if block_element.instruction.jump_type.as_deref() == Some("[in]") =>
+            {
+                destination.to_owned()
+            }
+            _ => return Ok(None),
+        };
+
+        let destination_index = block_stack.elements.len() - 1;
+        let (return_address_index, return_tag) = match block_stack.elements[..destination_index]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, element)| match element {
+                StackElement::Tag(tag) => Some((index, tag.to_owned())),
+                _ => None,
+            }) {
+            Some(return_address) => return_address,
+            None => return Ok(None),
+        };
+        if block_stack.elements[return_address_index + 1..destination_index]
+            .iter()
+            .any(|element| matches!(element, StackElement::ReturnAddress))
+        {
+            return Ok(None);
+        }
+        let input_size = destination_index - return_address_index - 1;
+        let block_key = &caller.block_key;
+        let entry_key = Self::destination_key(block_key.code_type, destination);
+
+        let signature_key = (entry_key.clone(), input_size);
+        if subroutines.in_progress.last() == Some(&signature_key) {
+            let name = Self::subroutine_name(&entry_key, input_size);
+            block_element.stack = block_stack.clone();
+            block_stack.elements.truncate(return_address_index);
+            subroutines.recursive_calls.push(RecursiveCall::new(
+                name.clone(),
+                caller.to_owned(),
+                input_size,
+                return_tag.clone(),
+                block_stack.to_owned(),
+            ));
+
+            let function_jump =
+                FunctionJump::call(name, input_size, 0, return_tag, block_stack.hash());
+            block_element.function_jump = Some(function_jump.clone());
+            return Ok(Some(function_jump));
+        }
+        if subroutines.in_progress.contains(&signature_key) {
+            anyhow::bail!(
+                "The subroutine {} is called recursively from another subroutine",
+                entry_key
+            );
+        }
+
+        let (name, output_size) =
+            match Self::recover_subroutine(version, blocks, entry_key, input_size, subroutines)? {
+                Some(signature) => signature,
+                None => return Ok(None),
+            };
+
+        block_element.stack = block_stack.clone();
+        block_stack.elements.truncate(return_address_index);
+        for _ in 0..output_size {
+            block_stack.push(StackElement::Value);
+        }
+        queue.push(QueueElement::new(
+            Self::destination_key(block_key.code_type, return_tag.clone()),
+            Some(block_key.to_owned()),
+            block_stack.to_owned(),
+        ));
+
+        let function_jump = FunctionJump::call(
+            name,
+            input_size,
+            output_size,
+            return_tag,
+            block_stack.hash(),
+        );
+        block_element.function_jump = Some(function_jump.clone());
+        Ok(Some(function_jump))
+    }
+
+    ///
+    /// Returns the name of the subroutine function starting at `entry_key` and taking
+    /// `input_size` arguments.
+    ///
+    fn subroutine_name(
+        entry_key: &compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+    ) -> String {
+        format!("function_{}_{}", entry_key, input_size)
+    }
+
+    ///
+    /// Returns the block key of the jump `destination`, where the tags above `u32::MAX` refer to
+    /// the runtime code from the deploy code.
+    ///
+    pub fn destination_key(
+        code_type: compiler_llvm_context::CodeType,
+        destination: num::BigUint,
+    ) -> compiler_llvm_context::FunctionBlockKey {
+        if destination > num::BigUint::from(u32::MAX) {
+            compiler_llvm_context::FunctionBlockKey::new(
+                compiler_llvm_context::CodeType::Runtime,
+                destination - num::BigUint::from(1u64 << 32),
+            )
+        } else {
+            compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
+        }
+    }
+
     ///
     /// Processes an instruction, returning an error, if there is an invalid stack state.
     ///
@@ -165,6 +590,7 @@ impl Function {
             Instruction {
                 name: InstructionName::PUSH_Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 let tag: num::BigUint = tag.parse().expect("Always valid");
                 block_stack.push(Element::Tag(tag.bitand(num::BigUint::from(u64::MAX))));
@@ -179,14 +605,7 @@ impl Function {
 
                 block_element.stack = block_stack.clone();
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    compiler_llvm_context::FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::destination_key(code_type, destination);
                 queue.push(QueueElement::new(
                     block_key,
                     queue_element.predecessor.clone(),
@@ -201,14 +620,7 @@ impl Function {
 
                 block_element.stack = block_stack.clone();
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    compiler_llvm_context::FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::destination_key(code_type, destination);
                 block_stack.pop()?;
                 queue.push(QueueElement::new(
                     block_key,
@@ -219,6 +631,7 @@ impl Function {
             Instruction {
                 name: InstructionName::Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 block_element.stack = block_stack.clone();
 
@@ -501,6 +914,7 @@ impl Function {
                     | InstructionName::PUSHLIB
                     | InstructionName::PUSHDEPLOYADDRESS,
                 value: Some(ref constant),
+                ..
             } => {
                 let element = match num::BigUint::from_str_radix(
                     constant.as_str(),
@@ -514,205 +928,43 @@ impl Function {
             }
 
             ref instruction @ Instruction {
-                name: InstructionName::ADD,
-                ..
-            } => {
-                let operand_2 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_1 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        match operand_1.checked_add(operand_2) {
-                            Some(result) => Element::Constant(result),
-                            None => Element::Value,
-                        }
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::SUB,
-                ..
-            } => {
-                let operand_2 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_1 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        match operand_1.checked_sub(operand_2) {
-                            Some(result) => Element::Constant(result),
-                            None => Element::Value,
-                        }
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::MUL,
-                ..
-            } => {
-                let operand_2 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_1 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        match operand_1.checked_mul(operand_2) {
-                            Some(result) => Element::Constant(result),
-                            None => Element::Value,
-                        }
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::SHL,
-                ..
-            } => {
-                let value = block_stack.elements.get(block_stack.elements.len() - 2);
-                let offset = block_stack.elements.last();
-
-                let result = match (value, offset) {
-                    (Some(Element::Tag(tag)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Tag(tag << offset)
-                    }
-                    (Some(Element::Constant(constant)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Constant(constant << offset)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::SHR,
-                ..
-            } => {
-                let value = block_stack.elements.get(block_stack.elements.len() - 2);
-                let offset = block_stack.elements.last();
-
-                let result = match (value, offset) {
-                    (Some(Element::Tag(tag)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Tag(tag >> offset)
-                    }
-                    (Some(Element::Constant(constant)), Some(Element::Constant(offset))) => {
-                        let offset = offset.to_u64().expect("Always valid");
-                        Element::Constant(constant >> offset)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::OR,
-                ..
-            } => {
-                let operand_1 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_2 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Tag(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Tag(operand_1)), Some(Element::Constant(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        Element::Tag(operand_1 | operand_2)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::XOR,
-                ..
-            } => {
-                let operand_1 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_2 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Tag(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Tag(operand_1)), Some(Element::Constant(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        Element::Tag(operand_1 ^ operand_2)
-                    }
-                    _ => Element::Value,
-                };
-
-                block_stack.push(result);
-                block_element.stack = block_stack.clone();
-                let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
-                    block_stack.pop()?;
-                }
-                block_stack.push(output);
-            }
-            ref instruction @ Instruction {
-                name: InstructionName::AND,
+                name:
+                    InstructionName::ADD
+                    | InstructionName::SUB
+                    | InstructionName::MUL
+                    | InstructionName::DIV
+                    | InstructionName::MOD
+                    | InstructionName::EXP
+                    | InstructionName::SIGNEXTEND
+                    | InstructionName::LT
+                    | InstructionName::GT
+                    | InstructionName::SLT
+                    | InstructionName::SGT
+                    | InstructionName::EQ
+                    | InstructionName::ISZERO
+                    | InstructionName::AND
+                    | InstructionName::OR
+                    | InstructionName::XOR
+                    | InstructionName::NOT
+                    | InstructionName::BYTE
+                    | InstructionName::SHL
+                    | InstructionName::SHR
+                    | InstructionName::SAR,
                 ..
             } => {
-                let operand_1 = block_stack.elements.get(block_stack.elements.len() - 2);
-                let operand_2 = block_stack.elements.last();
-
-                let result = match (operand_1, operand_2) {
-                    (Some(Element::Tag(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Tag(operand_1)), Some(Element::Constant(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Tag(operand_2)))
-                    | (Some(Element::Constant(operand_1)), Some(Element::Constant(operand_2))) => {
-                        Element::Tag(operand_1 & operand_2)
-                    }
-                    _ => Element::Value,
+                let input_size = instruction.input_size(version);
+                let operands: Vec<&Element> =
+                    block_stack.elements.iter().rev().take(input_size).collect();
+                let result = if operands.len() == input_size {
+                    constant_folding::fold(&instruction.name, operands.as_slice())
+                } else {
+                    Element::Value
                 };
 
                 block_stack.push(result);
                 block_element.stack = block_stack.clone();
                 let output = block_stack.pop()?;
-                for _ in 0..instruction.input_size(version) {
+                for _ in 0..input_size {
                     block_stack.pop()?;
                 }
                 block_stack.push(output);
@@ -742,7 +994,9 @@ impl Function {
     ///
     /// Pushes a block into the function.
     ///
-    fn insert_block(&mut self, block: Block) -> &mut Block {
+    /// Returns an error if the block is a clone exceeding the clone budget.
+    ///
+    fn insert_block(&mut self, block: Block) -> anyhow::Result<&mut Block> {
         let key = block.key.clone();
 
         if let Some(entry) = self.blocks.get_mut(&key) {
@@ -750,16 +1004,111 @@ impl Function {
                 existing_block.initial_stack.hash() != block.initial_stack.hash()
             }) {
                 entry.push(block);
+                self.clones += 1;
             }
         } else {
             self.blocks.insert(key.clone(), vec![block]);
         }
 
-        self.blocks
+        if let Some(clone_budget) = self.clone_budget {
+            if self.clones > clone_budget {
+                let hottest_tags: Vec<String> = self
+                    .clones_per_tag()
+                    .into_iter()
+                    .take(Stats::HOTTEST_TAGS_LIMIT)
+                    .map(|(key, clones)| format!("block_{} ({} clones)", key, clones))
+                    .collect();
+                return Err(CloneBudgetError::new(clone_budget, hottest_tags).into());
+            }
+        }
+
+        Ok(self
+            .blocks
             .get_mut(&key)
             .expect("Always exists")
             .last_mut()
-            .expect("Always exists")
+            .expect("Always exists"))
+    }
+
+    ///
+    /// Returns the number of clones per block tag, from the most cloned ones.
+    ///
+    fn clones_per_tag(&self) -> Vec<(compiler_llvm_context::FunctionBlockKey, usize)> {
+        let mut clones_per_tag: Vec<(compiler_llvm_context::FunctionBlockKey, usize)> = self
+            .blocks
+            .iter()
+            .filter(|(_key, blocks)| blocks.len() > 1)
+            .map(|(key, blocks)| (key.to_owned(), blocks.len() - 1))
+            .collect();
+        clones_per_tag.sort_by(|(_, clones_1), (_, clones_2)| clones_2.cmp(clones_1));
+        clones_per_tag
+    }
+
+    ///
+    /// Returns the function statistics.
+    ///
+    /// The clones per tag are counted after merging, whereas the total number of clones is
+    /// counted before.
+    ///
+    pub fn stats(&self) -> Stats {
+        Stats::new(
+            self.blocks.len(),
+            self.clones,
+            self.merged_clones,
+            self.stack_size,
+            self.clones_per_tag(),
+        )
+    }
+
+    ///
+    /// Merges the block clones with equal signatures, that is, the ones that only differ in
+    /// the tags which are never used as jump destinations. The merged clone is then identified by
+    /// the initial stack states of both.
+    ///
+    /// The merging is repeated until no more clones are merged, since merging the successors may
+    /// make their predecessors equal.
+    ///
+    fn merge_clones(&mut self) {
+        loop {
+            let mut representatives = HashMap::new();
+            for (key, blocks) in self.blocks.iter() {
+                for block in blocks.iter() {
+                    let hash = block.initial_stack.hash();
+                    for alias in block.aliases.iter() {
+                        representatives.insert(VisitedElement::new(key.to_owned(), *alias), hash);
+                    }
+                }
+            }
+
+            let mut merged_clones = 0;
+            for blocks in self.blocks.values_mut() {
+                if blocks.len() < 2 {
+                    continue;
+                }
+
+                let mut representative_blocks: Vec<(Option<_>, Block)> =
+                    Vec::with_capacity(blocks.len());
+                for block in blocks.drain(..) {
+                    let signature = block.signature(&representatives);
+                    match representative_blocks
+                        .iter_mut()
+                        .find(|(existing, _)| signature.is_some() && existing == &signature)
+                    {
+                        Some((_, representative)) => {
+                            representative.merge(block);
+                            merged_clones += 1;
+                        }
+                        None => representative_blocks.push((signature, block)),
+                    }
+                }
+                blocks.extend(representative_blocks.into_iter().map(|(_, block)| block));
+            }
+
+            if merged_clones == 0 {
+                break;
+            }
+            self.merged_clones += merged_clones;
+        }
     }
 
     ///
@@ -775,6 +1124,7 @@ impl Function {
                 }
             }
         }
+        self.merge_clones();
 
         self
     }
@@ -785,50 +1135,107 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        context.add_function_evm(
-            EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
-            context.void_type().fn_type(
-                &[context
-                    .integer_type(compiler_common::BITLENGTH_BOOLEAN as usize)
-                    .as_basic_type_enum()
-                    .into()],
-                false,
-            ),
-            Some(inkwell::module::Linkage::Private),
-            compiler_llvm_context::FunctionEVMData::new(self.stack_size),
-        );
+        match self.r#type {
+            Type::Main => {
+                context.add_function_evm(
+                    self.name.as_str(),
+                    context.void_type().fn_type(
+                        &[context
+                            .integer_type(compiler_common::BITLENGTH_BOOLEAN as usize)
+                            .as_basic_type_enum()
+                            .into()],
+                        false,
+                    ),
+                    Some(inkwell::module::Linkage::Private),
+                    compiler_llvm_context::FunctionEVMData::new(self.stack_size),
+                );
+            }
+            Type::Subroutine {
+                input_size,
+                output_size,
+                ..
+            } => {
+                let output_size = output_size.expect("Always exists");
+                let function_type = context.function_type(
+                    output_size,
+                    vec![context.field_type().as_basic_type_enum(); input_size],
+                );
+                context.add_function_evm(
+                    self.name.as_str(),
+                    function_type,
+                    Some(inkwell::module::Linkage::Private),
+                    compiler_llvm_context::FunctionEVMData::new(self.stack_size),
+                );
+
+                if output_size > 1 {
+                    let function = context
+                        .functions
+                        .get(self.name.as_str())
+                        .cloned()
+                        .expect("Always exists");
+                    let pointer = function
+                        .value
+                        .get_first_param()
+                        .expect("Always exists")
+                        .into_pointer_value();
+                    context.set_function(function);
+                    context.set_function_return(compiler_llvm_context::FunctionReturn::compound(
+                        pointer,
+                        output_size,
+                    ));
+                }
+            }
+        }
 
         Ok(())
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Function
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         let function = context
             .functions
-            .get(EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME)
+            .get(self.name.as_str())
             .cloned()
             .expect("Always exists");
-        let is_deploy_code_flag = function
-            .value
-            .get_first_param()
-            .expect("Always exists")
-            .into_int_value();
-        context.set_function(function);
+        context.set_function(function.clone());
 
+        let mut llvm_blocks = BTreeMap::new();
         for (key, blocks) in self.blocks.iter() {
+            let mut key_llvm_blocks = Vec::with_capacity(blocks.len());
             for (index, block) in blocks.iter().enumerate() {
                 let inner = context.append_basic_block(format!("block_{}/{}", key, index).as_str());
-                let block = compiler_llvm_context::FunctionBlock::new_evm(
-                    inner,
-                    compiler_llvm_context::FunctionBlockEVMData::new(block.initial_stack.hash()),
-                );
-                context
-                    .function_mut()
-                    .evm_mut()
-                    .insert_block(key.to_owned(), block);
+                for hash in
+                    std::iter::once(block.initial_stack.hash()).chain(block.aliases.iter().copied())
+                {
+                    context.function_mut().evm_mut().insert_block(
+                        key.to_owned(),
+                        compiler_llvm_context::FunctionBlock::new_evm(
+                            inner,
+                            compiler_llvm_context::FunctionBlockEVMData::new(hash),
+                        ),
+                    );
+                }
+                key_llvm_blocks.push(inner);
             }
+            llvm_blocks.insert(key.to_owned(), key_llvm_blocks);
         }
 
         context.set_basic_block(context.function().entry_block);
+        if let Some(debug_info) = debug_info {
+            debug_info.set_function_location(context, 0);
+        }
         let mut stack_variables = Vec::with_capacity(self.stack_size);
         for stack_index in 0..self.stack_size {
             let pointer = context.build_alloca(
@@ -841,45 +1248,104 @@ where
         }
         context.evm_mut().stack = stack_variables;
 
-        let deploy_code_block = context.function().evm().find_block(
-            &compiler_llvm_context::FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Deploy,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        let runtime_code_block = context.function().evm().find_block(
-            &compiler_llvm_context::FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Runtime,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        context.build_conditional_branch(
-            is_deploy_code_flag,
-            deploy_code_block.inner,
-            runtime_code_block.inner,
-        );
+        match self.r#type {
+            Type::Main => {
+                let is_deploy_code_flag = function
+                    .value
+                    .get_first_param()
+                    .expect("Always exists")
+                    .into_int_value();
+                let deploy_code_block = context.function().evm().find_block(
+                    &compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Deploy,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                let runtime_code_block = context.function().evm().find_block(
+                    &compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Runtime,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                context.build_conditional_branch(
+                    is_deploy_code_flag,
+                    deploy_code_block.inner,
+                    runtime_code_block.inner,
+                );
+            }
+            Type::Subroutine {
+                ref entry_key,
+                input_size,
+                output_size,
+            } => {
+                if output_size == Some(1) {
+                    let pointer = context.build_alloca(context.field_type(), "return_pointer");
+                    context.build_store(pointer, context.field_const(0));
+                    context.set_function_return(compiler_llvm_context::FunctionReturn::primitive(
+                        pointer,
+                    ));
+                }
+
+                let argument_offset = match context.function().r#return {
+                    Some(compiler_llvm_context::FunctionReturn::Compound { .. }) => 1,
+                    _ => 0,
+                };
+                for index in 0..input_size {
+                    let pointer = context.evm().stack[index + 1]
+                        .to_llvm()
+                        .into_pointer_value();
+                    let argument = context
+                        .function()
+                        .value
+                        .get_nth_param((argument_offset + index) as u32)
+                        .expect("Always exists");
+                    context.build_store(pointer, argument);
+                }
+
+                let mut initial_stack = Stack::new();
+                initial_stack.push(StackElement::ReturnAddress);
+                for _ in 0..input_size {
+                    initial_stack.push(StackElement::Value);
+                }
+                let entry_block = context
+                    .function()
+                    .evm()
+                    .find_block(entry_key, &initial_stack.hash())?;
+                context.build_unconditional_branch(entry_block.inner);
+            }
+        }
 
         for (key, blocks) in self.blocks.into_iter() {
-            for (llvm_block, ir_block) in context
-                .function()
-                .evm()
-                .blocks
-                .get(&key)
-                .cloned()
+            for (llvm_block, ir_block) in llvm_blocks
+                .remove(&key)
                 .ok_or_else(|| anyhow::anyhow!("Undeclared function block {}", key))?
                 .into_iter()
-                .map(|block| block.inner)
                 .zip(blocks)
             {
                 context.set_basic_block(llvm_block);
-                ir_block.into_llvm(context)?;
+                ir_block.into_llvm_debug(context, debug_info)?;
             }
         }
 
         context.set_basic_block(context.function().return_block);
-        context.build_return(None);
+        if let Some(debug_info) = debug_info {
+            debug_info.set_location(context, None, 0, 0);
+        }
+        match context.function().r#return {
+            Some(compiler_llvm_context::FunctionReturn::Primitive { pointer }) => {
+                let return_value = context.build_load(pointer, "return_value");
+                context.build_return(Some(&return_value));
+            }
+            Some(compiler_llvm_context::FunctionReturn::Compound { pointer, .. }) => {
+                context.build_return(Some(&pointer));
+            }
+            _ => context.build_return(None),
+        }
+        if let Some(debug_info) = debug_info {
+            debug_info.unset_location(context);
+        }
 
         Ok(())
     }
@@ -887,7 +1353,14 @@ where
 
 impl std::fmt::Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?;
+        match self.r#type {
+            Type::Main => writeln!(f, "function main (max_sp = {}) {{", self.stack_size)?,
+            Type::Subroutine { .. } => writeln!(
+                f,
+                "function {} (max_sp = {}) {{",
+                self.name, self.stack_size
+            )?,
+        }
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
                 writeln!(
@@ -913,3 +1386,252 @@ impl std::fmt::Display for Function {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::evmla::assembly::instruction::name::Name as InstructionName;
+    use crate::evmla::assembly::instruction::Instruction;
+    use crate::evmla::ethereal_ir::function::block::element::function_jump::FunctionJump;
+    use crate::evmla::ethereal_ir::function::r#type::Type;
+    use crate::evmla::ethereal_ir::EtherealIR;
+
+    fn instruction(name: InstructionName, value: Option<&str>) -> Instruction {
+        let mut instruction = Instruction::from(name);
+        instruction.value = value.map(str::to_owned);
+        instruction
+    }
+
+    fn jump(jump_type: &str) -> Instruction {
+        let mut instruction = Instruction::from(InstructionName::JUMP);
+        instruction.jump_type = Some(jump_type.to_owned());
+        instruction
+    }
+
+    fn ethereal_ir(
+        deploy_code: Vec<Instruction>,
+        clone_budget: Option<usize>,
+    ) -> anyhow::Result<EtherealIR> {
+        let version = semver::Version::new(0, 8, 17);
+        let mut blocks = EtherealIR::get_blocks(
+            version.clone(),
+            false,
+            compiler_llvm_context::CodeType::Deploy,
+            deploy_code.as_slice(),
+        )?;
+        blocks.extend(EtherealIR::get_blocks(
+            version.clone(),
+            false,
+            compiler_llvm_context::CodeType::Runtime,
+            &[Instruction::from(InstructionName::STOP)],
+        )?);
+        EtherealIR::new(version, blocks, clone_budget)
+    }
+
+    fn deploy_key(tag: u64) -> compiler_llvm_context::FunctionBlockKey {
+        compiler_llvm_context::FunctionBlockKey::new(
+            compiler_llvm_context::CodeType::Deploy,
+            num::BigUint::from(tag),
+        )
+    }
+
+    ///
+    /// Enters the block `3` twice, with the tags `1` and `2` on the stack, and appends the `tail`
+    /// of the block.
+    ///
+    fn two_clones(mut tail: Vec<Instruction>) -> Vec<Instruction> {
+        let mut code = vec![
+            instruction(InstructionName::PUSH_Tag, Some("1")),
+            instruction(InstructionName::CALLVALUE, None),
+            instruction(InstructionName::PUSH_Tag, Some("3")),
+            instruction(InstructionName::JUMPI, None),
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::PUSH_Tag, Some("2")),
+            instruction(InstructionName::PUSH_Tag, Some("3")),
+            instruction(InstructionName::JUMP, None),
+            instruction(InstructionName::Tag, Some("3")),
+        ];
+        code.append(&mut tail);
+        code
+    }
+
+    #[test]
+    fn merge_clones_dead_tag() {
+        let code = two_clones(vec![
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::STOP, None),
+        ]);
+
+        let ethereal_ir = ethereal_ir(code, None).expect("Always valid");
+        let blocks = &ethereal_ir.function.blocks[&deploy_key(3)];
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].aliases.len(), 1);
+        assert_eq!(ethereal_ir.function.clones, 1);
+        assert_eq!(ethereal_ir.function.merged_clones, 1);
+    }
+
+    #[test]
+    fn merge_clones_different_successors() {
+        let code = two_clones(vec![
+            instruction(InstructionName::JUMP, None),
+            instruction(InstructionName::Tag, Some("1")),
+            instruction(InstructionName::STOP, None),
+            instruction(InstructionName::Tag, Some("2")),
+            instruction(InstructionName::STOP, None),
+        ]);
+
+        let ethereal_ir = ethereal_ir(code, None).expect("Always valid");
+        let blocks = &ethereal_ir.function.blocks[&deploy_key(3)];
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks.iter().all(|block| block.aliases.is_empty()));
+        assert_eq!(ethereal_ir.function.merged_clones, 0);
+    }
+
+    #[test]
+    fn clone_budget_exceeded() {
+        let code = two_clones(vec![
+            instruction(InstructionName::JUMP, None),
+            instruction(InstructionName::Tag, Some("1")),
+            instruction(InstructionName::STOP, None),
+            instruction(InstructionName::Tag, Some("2")),
+            instruction(InstructionName::STOP, None),
+        ]);
+
+        assert!(ethereal_ir(code.clone(), Some(1)).is_ok());
+        let error = ethereal_ir(code, Some(0))
+            .expect_err("Always fails")
+            .to_string();
+        assert!(error.contains("The Ethereal IR block clone budget of 0 is exceeded"));
+        assert!(error.contains(format!("block_{} (1 clones)", deploy_key(3)).as_str()));
+    }
+
+    #[test]
+    fn recovered_call() {
+        let code = vec![
+            instruction(InstructionName::PUSH_Tag, Some("1")),
+            instruction(InstructionName::CALLVALUE, None),
+            instruction(InstructionName::CALLVALUE, None),
+            instruction(InstructionName::PUSH_Tag, Some("2")),
+            jump("[in]"),
+            instruction(InstructionName::Tag, Some("1")),
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::STOP, None),
+            instruction(InstructionName::Tag, Some("2")),
+            instruction(InstructionName::SWAP2, None),
+            jump("[out]"),
+        ];
+
+        let ethereal_ir = ethereal_ir(code, None).expect("Always valid");
+        let name = format!("function_{}_2", deploy_key(2));
+        assert_eq!(ethereal_ir.functions.len(), 1);
+        assert!(matches!(
+            ethereal_ir.functions[&name].r#type,
+            Type::Subroutine {
+                input_size: 2,
+                output_size: Some(2),
+                ..
+            }
+        ));
+        assert!(!ethereal_ir.function.blocks.contains_key(&deploy_key(2)));
+
+        let caller = &ethereal_ir.function.blocks[&deploy_key(0)][0];
+        assert!(matches!(
+            caller
+                .elements
+                .last()
+                .and_then(|element| element.function_jump.as_ref()),
+            Some(FunctionJump::Call {
+                input_size: 2,
+                output_size: 2,
+                ..
+            })
+        ));
+        let successors = caller.successors().expect("Always exists");
+        assert_eq!(successors.len(), 1);
+        assert_eq!(successors[0].1.block_key, deploy_key(1));
+        assert_eq!(ethereal_ir.stats().blocks, 3);
+    }
+
+    #[test]
+    fn unrecovered_call_inlined() {
+        let code = vec![
+            instruction(InstructionName::PUSH_Tag, Some("1")),
+            instruction(InstructionName::CALLVALUE, None),
+            instruction(InstructionName::PUSH_Tag, Some("2")),
+            jump("[in]"),
+            instruction(InstructionName::Tag, Some("1")),
+            instruction(InstructionName::STOP, None),
+            instruction(InstructionName::Tag, Some("2")),
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::STOP, None),
+        ];
+
+        let ethereal_ir = ethereal_ir(code, None).expect("Always valid");
+        assert!(ethereal_ir.functions.is_empty());
+        assert!(ethereal_ir.function.blocks.contains_key(&deploy_key(2)));
+        assert!(ethereal_ir.truncations().is_empty());
+
+        let caller = &ethereal_ir.function.blocks[&deploy_key(0)][0];
+        assert!(caller
+            .elements
+            .last()
+            .and_then(|element| element.function_jump.as_ref())
+            .is_none());
+    }
+
+    #[test]
+    fn recursive_call() {
+        let code = vec![
+            instruction(InstructionName::PUSH_Tag, Some("1")),
+            instruction(InstructionName::CALLVALUE, None),
+            instruction(InstructionName::PUSH_Tag, Some("2")),
+            jump("[in]"),
+            instruction(InstructionName::Tag, Some("1")),
+            instruction(InstructionName::POP, None),
+            instruction(InstructionName::STOP, None),
+            instruction(InstructionName::Tag, Some("2")),
+            instruction(InstructionName::DUP1, None),
+            instruction(InstructionName::PUSH_Tag, Some("4")),
+            instruction(InstructionName::JUMPI, None),
+            instruction(InstructionName::SWAP1, None),
+            jump("[out]"),
+            instruction(InstructionName::Tag, Some("4")),
+            instruction(InstructionName::PUSH_Tag, Some("3")),
+            instruction(InstructionName::SWAP1, None),
+            instruction(InstructionName::PUSH_Tag, Some("2")),
+            jump("[in]"),
+            instruction(InstructionName::Tag, Some("3")),
+            instruction(InstructionName::SWAP1, None),
+            jump("[out]"),
+        ];
+
+        let ethereal_ir = ethereal_ir(code, None).expect("Always valid");
+        let name = format!("function_{}_1", deploy_key(2));
+        assert_eq!(ethereal_ir.functions.len(), 1);
+        let function = &ethereal_ir.functions[&name];
+        assert!(matches!(
+            function.r#type,
+            Type::Subroutine {
+                input_size: 1,
+                output_size: Some(1),
+                ..
+            }
+        ));
+
+        let caller = &function.blocks[&deploy_key(4)][0];
+        assert!(matches!(
+            caller.elements.last().and_then(|element| element.function_jump.as_ref()),
+            Some(FunctionJump::Call {
+                name: callee,
+                input_size: 1,
+                output_size: 1,
+                ..
+            }) if callee == &name
+        ));
+        let successors = caller.successors().expect("Always exists");
+        assert_eq!(successors.len(), 1);
+        assert_eq!(successors[0].1.block_key, deploy_key(3));
+        assert!(function.blocks.contains_key(&deploy_key(3)));
+    }
+}
@@ -0,0 +1,47 @@
+//!
+//! The Ethereal IR recursive subroutine call.
+//!
+
+use crate::evmla::ethereal_ir::function::block::element::stack::Stack;
+use crate::evmla::ethereal_ir::function::visited_element::VisitedElement;
+
+///
+/// The Ethereal IR recursive subroutine call.
+///
+/// A subroutine called from itself does not know its number of return values yet, so the block
+/// it returns to is only analyzed after the subroutine is proven to return.
+///
+#[derive(Debug, Clone)]
+pub struct RecursiveCall {
+    /// The called function name.
+    pub function_name: String,
+    /// The calling block clone.
+    pub caller: VisitedElement,
+    /// The number of the function arguments.
+    pub input_size: usize,
+    /// The return destination tag.
+    pub return_tag: num::BigUint,
+    /// The stack at the return destination without the return values.
+    pub return_stack: Stack,
+}
+
+impl RecursiveCall {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        function_name: String,
+        caller: VisitedElement,
+        input_size: usize,
+        return_tag: num::BigUint,
+        return_stack: Stack,
+    ) -> Self {
+        Self {
+            function_name,
+            caller,
+            input_size,
+            return_tag,
+            return_stack,
+        }
+    }
+}
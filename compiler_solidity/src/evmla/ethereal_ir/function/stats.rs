@@ -0,0 +1,74 @@
+//!
+//! The Ethereal IR function statistics.
+//!
+
+///
+/// The Ethereal IR function statistics.
+///
+#[derive(Debug, Clone)]
+pub struct Stats {
+    /// The number of distinct block tags.
+    pub blocks: usize,
+    /// The number of block clones, that is, the blocks beyond the first one of each tag.
+    pub clones: usize,
+    /// The number of clones merged into identical ones.
+    pub merged_clones: usize,
+    /// The maximum stack depth.
+    pub max_stack_depth: usize,
+    /// The number of clones per block tag, from the most cloned ones.
+    pub clones_per_tag: Vec<(compiler_llvm_context::FunctionBlockKey, usize)>,
+}
+
+impl Stats {
+    /// The number of the most cloned blocks printed.
+    pub const HOTTEST_TAGS_LIMIT: usize = 10;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        blocks: usize,
+        clones: usize,
+        merged_clones: usize,
+        max_stack_depth: usize,
+        clones_per_tag: Vec<(compiler_llvm_context::FunctionBlockKey, usize)>,
+    ) -> Self {
+        Self {
+            blocks,
+            clones,
+            merged_clones,
+            max_stack_depth,
+            clones_per_tag,
+        }
+    }
+
+    ///
+    /// Adds the statistics of another function, e.g. a recovered subroutine.
+    ///
+    pub fn extend(&mut self, other: Self) {
+        self.blocks += other.blocks;
+        self.clones += other.clones;
+        self.merged_clones += other.merged_clones;
+        self.max_stack_depth = std::cmp::max(self.max_stack_depth, other.max_stack_depth);
+        self.clones_per_tag.extend(other.clones_per_tag);
+        self.clones_per_tag
+            .sort_by(|(_, clones_1), (_, clones_2)| clones_2.cmp(clones_1));
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:24}{}", "blocks:", self.blocks)?;
+        writeln!(f, "{:24}{}", "clones:", self.clones)?;
+        writeln!(f, "{:24}{}", "merged clones:", self.merged_clones)?;
+        writeln!(f, "{:24}{}", "max stack depth:", self.max_stack_depth)?;
+        if !self.clones_per_tag.is_empty() {
+            writeln!(f, "most cloned blocks:")?;
+            for (key, clones) in self.clones_per_tag.iter().take(Self::HOTTEST_TAGS_LIMIT) {
+                writeln!(f, "    {:20}{}", format!("block_{}", key), clones)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//!
+//! The Ethereal IR recovered subroutines.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::evmla::ethereal_ir::function::recursive_call::RecursiveCall;
+use crate::evmla::ethereal_ir::function::Function;
+
+///
+/// The Ethereal IR recovered subroutines.
+///
+/// Shared by the whole contract, so every subroutine is analyzed once for all its call sites.
+///
+#[derive(Debug)]
+pub struct Subroutines {
+    /// The maximum number of block clones per function, if limited.
+    pub clone_budget: Option<usize>,
+    /// The recovered functions by their names.
+    pub functions: BTreeMap<String, Function>,
+    /// The recovery results by the entry block key and the number of arguments, which are the
+    /// function names and numbers of return values.
+    ///
+    /// `None` means that the subroutine is being analyzed or cannot be recovered.
+    pub signatures:
+        HashMap<(compiler_llvm_context::FunctionBlockKey, usize), Option<(String, usize)>>,
+    /// The subroutines being analyzed, from the outermost one, by the entry block key and the
+    /// number of arguments.
+    pub in_progress: Vec<(compiler_llvm_context::FunctionBlockKey, usize)>,
+    /// The recursive calls of the subroutines being analyzed.
+    pub recursive_calls: Vec<RecursiveCall>,
+}
+
+impl Subroutines {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(clone_budget: Option<usize>) -> Self {
+        Self {
+            clone_budget,
+            functions: BTreeMap::new(),
+            signatures: HashMap::new(),
+            in_progress: Vec::new(),
+            recursive_calls: Vec::new(),
+        }
+    }
+}
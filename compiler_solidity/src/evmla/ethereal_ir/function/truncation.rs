@@ -0,0 +1,57 @@
+//!
+//! The Ethereal IR block truncation.
+//!
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The Ethereal IR block truncation.
+///
+/// The block is truncated as unreachable at the instruction with an invalid stack state, which
+/// may be a translator bug instead of the dead code, since the truncated part reverts at runtime.
+///
+#[derive(Debug, Clone)]
+pub struct Truncation {
+    /// The truncated block key.
+    pub block_key: compiler_llvm_context::FunctionBlockKey,
+    /// The index of the instruction replaced with `INVALID` in the block.
+    pub index: usize,
+    /// The replaced instruction name.
+    pub instruction: InstructionName,
+    /// The invalid stack state description.
+    pub reason: String,
+    /// The Solidity source code location of the replaced instruction, if available.
+    pub location: Option<SourceLocation>,
+}
+
+impl Truncation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        block_key: compiler_llvm_context::FunctionBlockKey,
+        index: usize,
+        instruction: InstructionName,
+        reason: String,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self {
+            block_key,
+            index,
+            instruction,
+            reason,
+            location,
+        }
+    }
+}
+
+impl std::fmt::Display for Truncation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The Ethereal IR block `block_{}` is truncated as unreachable at the instruction {} `{:?}`: {}",
+            self.block_key, self.index, self.instruction, self.reason
+        )
+    }
+}
@@ -0,0 +1,21 @@
+//!
+//! The Ethereal IR function type.
+//!
+
+///
+/// The Ethereal IR function type.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    /// The all-inlined entry function, which selects the deploy or runtime code.
+    Main,
+    /// The function recovered from the EVM legacy assembly subroutine.
+    Subroutine {
+        /// The subroutine entry block key.
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        /// The number of the subroutine arguments.
+        input_size: usize,
+        /// The number of the subroutine return values, unknown until a return is reached.
+        output_size: Option<usize>,
+    },
+}
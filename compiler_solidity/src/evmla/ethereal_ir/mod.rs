@@ -5,12 +5,18 @@
 pub mod entry_link;
 pub mod function;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::evmla::assembly::instruction::Instruction;
 
 use self::function::block::Block;
+use self::function::stats::Stats;
+use self::function::subroutines::Subroutines;
+use self::function::truncation::Truncation;
 use self::function::Function;
 
 ///
@@ -25,12 +31,17 @@ use self::function::Function;
 /// each of initial states of the stack. The LLVM IR supports only static control flow, so the
 /// stack state must be known all the way throughout the program.
 ///
-#[derive(Debug)]
+/// The subroutines which are proven to be called and returned from like functions are recovered
+/// into separate functions instead of being inlined for each stack state of their callers.
+///
+#[derive(Debug, Clone)]
 pub struct EtherealIR {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
-    /// The all-inlined function representation.
+    /// The entry function representation, where the unrecovered subroutines are inlined.
     pub function: Function,
+    /// The recovered subroutine functions by their names.
+    pub functions: BTreeMap<String, Function>,
 }
 
 impl EtherealIR {
@@ -43,24 +54,60 @@ impl EtherealIR {
     ///
     /// Assembles a sequence of functions from the sequence of instructions.
     ///
+    /// If `clone_budget` is set, the assembly fails if a function requires more block clones.
+    ///
     pub fn new(
         solc_version: semver::Version,
         blocks: HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        clone_budget: Option<usize>,
     ) -> anyhow::Result<Self> {
         let mut visited = HashSet::with_capacity(blocks.len());
-        let function = Function::new(solc_version.clone(), &blocks, &mut visited)?;
+        let mut subroutines = Subroutines::new(clone_budget);
+        let function = Function::new(
+            solc_version.clone(),
+            &blocks,
+            &mut visited,
+            &mut subroutines,
+        )?;
 
         Ok(Self {
             solc_version,
             function,
+            functions: subroutines.functions,
         })
     }
 
+    ///
+    /// Returns the block duplication statistics of the entry function and the recovered
+    /// subroutines altogether.
+    ///
+    pub fn stats(&self) -> Stats {
+        let mut stats = self.function.stats();
+        for function in self.functions.values() {
+            stats.extend(function.stats());
+        }
+        stats
+    }
+
+    ///
+    /// Returns the blocks truncated as unreachable because of an invalid stack state.
+    ///
+    pub fn truncations(&self) -> Vec<Truncation> {
+        std::iter::once(&self.function)
+            .chain(self.functions.values())
+            .flat_map(|function| function.truncations.iter().cloned())
+            .collect()
+    }
+
     ///
     /// Gets blocks for the specified type of the contract code.
     ///
+    /// If `is_lenient` is set, the unsupported instructions are translated with the EVM semantics
+    /// substitutions instead of failing.
+    ///
     pub fn get_blocks(
         solc_version: semver::Version,
+        is_lenient: bool,
         code_type: compiler_llvm_context::CodeType,
         instructions: &[Instruction],
     ) -> anyhow::Result<HashMap<compiler_llvm_context::FunctionBlockKey, Block>> {
@@ -70,6 +117,7 @@ impl EtherealIR {
         while offset < instructions.len() {
             let (block, size) = Block::try_from_instructions(
                 solc_version.clone(),
+                is_lenient,
                 code_type,
                 &instructions[offset..],
             )?;
@@ -90,14 +138,33 @@ where
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         self.function.declare(context)?;
+        for function in self.functions.values_mut() {
+            function.declare(context)?;
+        }
 
         Ok(())
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for EtherealIR
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         context.evm_mut().stack = vec![];
 
-        self.function.into_llvm(context)?;
+        self.function.into_llvm_debug(context, debug_info)?;
+        for function in self.functions.into_values() {
+            function.into_llvm_debug(context, debug_info)?;
+        }
 
         Ok(())
     }
@@ -106,6 +173,9 @@ where
 impl std::fmt::Display for EtherealIR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.function)?;
+        for function in self.functions.values() {
+            writeln!(f, "{}", function)?;
+        }
 
         Ok(())
     }
@@ -3,19 +3,41 @@
 //!
 
 pub(crate) mod build;
+pub(crate) mod compat_report;
 pub(crate) mod r#const;
+pub(crate) mod debug_info;
+pub(crate) mod diagnostic;
 pub(crate) mod dump_flag;
 pub(crate) mod evmla;
+pub(crate) mod metadata;
 pub(crate) mod project;
+pub(crate) mod size_report;
 pub(crate) mod solc;
+pub(crate) mod source_map;
+pub(crate) mod verification;
+pub(crate) mod warning;
 pub(crate) mod yul;
+pub(crate) mod zkasm;
 
 pub use self::build::contract::Contract as ContractBuild;
+pub use self::build::info::Info as BuildInfo;
 pub use self::build::Build;
+pub use self::compat_report::substitution::Substitution as CompatReportSubstitution;
+pub use self::compat_report::CompatReport;
+pub use self::debug_info::DebugInfo;
+pub use self::diagnostic::collection::Collection as DiagnosticCollection;
+pub use self::diagnostic::format::Format as DiagnosticFormat;
+pub use self::diagnostic::span::Span as DiagnosticSpan;
+pub use self::diagnostic::Diagnostic;
 pub use self::dump_flag::DumpFlag;
+pub use self::evmla::ethereal_ir::function::cfg::format::Format as EthIRCFGFormat;
+pub use self::metadata::hash::Hash as MetadataHash;
+pub use self::metadata::Metadata;
 pub use self::project::contract::state::State as ContractState;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
+pub use self::size_report::format::Format as SizeReportFormat;
+pub use self::size_report::SizeReport;
 pub use self::solc::combined_json::contract::Contract as SolcCombinedJsonContract;
 pub use self::solc::combined_json::CombinedJson as SolcCombinedJson;
 pub use self::solc::pipeline::Pipeline as SolcPipeline;
@@ -27,6 +49,13 @@ pub use self::solc::standard_json::input::Input as SolcStandardJsonInput;
 pub use self::solc::standard_json::output::contract::evm::bytecode::Bytecode as SolcStandardJsonOutputContractEVMBytecode;
 pub use self::solc::standard_json::output::contract::evm::EVM as SolcStandardJsonOutputContractEVM;
 pub use self::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
+pub use self::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 pub use self::solc::standard_json::output::Output as SolcStandardJsonOutput;
 pub use self::solc::version::Version as SolcVersion;
 pub use self::solc::Compiler as SolcCompiler;
+pub use self::source_map::SourceMap;
+pub use self::verification::Verification;
+pub use self::warning::settings::Settings as WarningSettings;
+pub use self::warning::Warning;
+pub use self::zkasm::disassembly::Disassembly as ZkAsmDisassembly;
+pub use self::zkasm::read_bytecode;
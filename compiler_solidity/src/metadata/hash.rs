@@ -0,0 +1,44 @@
+//!
+//! The contract metadata hash type.
+//!
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contract metadata hash type.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Hash {
+    /// The metadata hash is not appended to the bytecode.
+    None,
+    /// The metadata `keccak256` hash is appended to the bytecode.
+    Keccak256,
+}
+
+impl FromStr for Hash {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(Self::None),
+            "keccak256" => Ok(Self::Keccak256),
+            value => anyhow::bail!(
+                "Invalid metadata hash type `{}`. Available types: none, keccak256",
+                value
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Keccak256 => write!(f, "keccak256"),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//!
+//! The contract metadata.
+//!
+
+pub mod hash;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contract metadata.
+///
+/// The `solc` metadata extended with the `zksolc` settings, which affect the bytecode.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The `solc` metadata, if produced.
+    pub solc_metadata: Option<serde_json::Value>,
+    /// The `solc` version.
+    pub solc_version: semver::Version,
+    /// The `zksolc` version.
+    pub zk_version: semver::Version,
+    /// The LLVM optimizer mode, that is, `cycles` or `none`.
+    pub optimizer_mode: String,
+    /// The LLVM options.
+    pub llvm_options: Vec<String>,
+}
+
+impl Metadata {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The `solc` metadata is parsed from its JSON string, and kept as a string if it is invalid.
+    ///
+    pub fn new(
+        solc_metadata: Option<String>,
+        solc_version: semver::Version,
+        is_optimized: bool,
+        llvm_options: Vec<String>,
+    ) -> Self {
+        Self {
+            solc_metadata: solc_metadata.map(|metadata| {
+                serde_json::from_str(metadata.as_str())
+                    .unwrap_or(serde_json::Value::String(metadata))
+            }),
+            solc_version,
+            zk_version: semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid"),
+            optimizer_mode: if is_optimized { "cycles" } else { "none" }.to_owned(),
+            llvm_options,
+        }
+    }
+
+    ///
+    /// Returns the metadata `keccak256` hash.
+    ///
+    pub fn keccak256(&self) -> String {
+        compiler_llvm_context::keccak256(self.to_string().as_bytes())
+    }
+
+    ///
+    /// Appends the metadata hash to the contract bytecode and updates the bytecode hash.
+    ///
+    /// The hash word is preceded by a zero word to keep the odd number of words, so the hash is
+    /// always the bytecode suffix.
+    ///
+    pub fn append_hash(
+        &self,
+        path: &str,
+        build: &mut compiler_llvm_context::Build,
+    ) -> anyhow::Result<()> {
+        let hash = hex::decode(self.keccak256()).expect("Always valid");
+        build.bytecode.extend(vec![0; compiler_common::SIZE_FIELD]);
+        build.bytecode.extend(hash);
+
+        build.hash = crate::zkasm::bytecode_hash(path, build.bytecode.as_slice())?;
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Metadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).expect("Always valid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metadata;
+
+    #[test]
+    fn solc_metadata() {
+        let version = semver::Version::new(0, 8, 17);
+
+        let metadata = Metadata::new(
+            Some(r#"{"language":"Solidity"}"#.to_owned()),
+            version.clone(),
+            true,
+            vec![],
+        );
+        assert_eq!(
+            metadata.solc_metadata,
+            Some(serde_json::json!({ "language": "Solidity" }))
+        );
+        assert_eq!(metadata.optimizer_mode, "cycles");
+
+        let metadata = Metadata::new(Some("invalid".to_owned()), version, false, vec![]);
+        assert_eq!(
+            metadata.solc_metadata,
+            Some(serde_json::Value::String("invalid".to_owned()))
+        );
+        assert_eq!(metadata.optimizer_mode, "none");
+    }
+}
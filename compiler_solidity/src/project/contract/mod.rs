@@ -11,8 +11,15 @@ use std::sync::RwLock;
 
 use compiler_llvm_context::WriteLLVM;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::dump_flag::DumpFlag;
+use crate::metadata::hash::Hash as MetadataHash;
+use crate::metadata::Metadata;
 use crate::project::Project;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::source_map::SourceMap;
+use crate::warning::Warning;
 
 use self::source::Source;
 use self::state::State;
@@ -28,14 +35,26 @@ pub struct Contract {
     pub source: Source,
     /// The ABI specification JSON.
     pub abi: Option<serde_json::Value>,
+    /// The `solc` metadata JSON string.
+    pub metadata: Option<String>,
 }
 
 impl Contract {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(path: String, source: Source, abi: Option<serde_json::Value>) -> Self {
-        Self { path, source, abi }
+    pub fn new(
+        path: String,
+        source: Source,
+        abi: Option<serde_json::Value>,
+        metadata: Option<String>,
+    ) -> Self {
+        Self {
+            path,
+            source,
+            abi,
+            metadata,
+        }
     }
 
     ///
@@ -62,14 +81,22 @@ impl Contract {
     }
 
     ///
-    /// Compiles the specified contract, setting its build artifacts.
+    /// Compiles the specified contract, returning its build artifacts, bytecode source map,
+    /// metadata, and the warnings found during the translation.
+    ///
+    /// The metadata hash is appended to the bytecode if requested by the project.
     ///
     pub fn compile(
         mut self,
         project: Arc<RwLock<Project>>,
         optimizer_settings: compiler_llvm_context::OptimizerSettings,
         dump_flags: Vec<DumpFlag>,
-    ) -> anyhow::Result<compiler_llvm_context::Build> {
+    ) -> anyhow::Result<(
+        compiler_llvm_context::Build,
+        SourceMap,
+        Metadata,
+        Vec<SolcStandardJsonOutputError>,
+    )> {
         let llvm = inkwell::context::Context::create();
         let optimizer = compiler_llvm_context::Optimizer::new(optimizer_settings)?;
         let dump_flags = compiler_llvm_context::DumpFlag::initialize(
@@ -88,8 +115,24 @@ impl Contract {
                 Some(project.clone()),
                 dump_flags,
             ),
-            Source::EVM(_) => {
-                let version = project.read().expect("Sync").version.to_owned();
+            Source::EVM(ref mut source) => {
+                let (version, is_lenient, ethir_clone_budget, ethir_cfg_dump, is_ethir_strict) = {
+                    let project = project.read().expect("Sync");
+                    (
+                        project.version.to_owned(),
+                        project.is_evmla_lenient,
+                        project.ethir_clone_budget,
+                        project.ethir_cfg_dump.clone(),
+                        project.is_ethir_strict,
+                    )
+                };
+                source.assembly.set_lenient(is_lenient);
+                source.assembly.set_ethir_clone_budget(ethir_clone_budget);
+                source.assembly.set_ethir_cfg_dump(ethir_cfg_dump);
+                source
+                    .assembly
+                    .set_ethir_stats_dumped(dump_flags.contains(&DumpFlag::EthIRStats));
+                source.assembly.set_ethir_strict(is_ethir_strict);
                 compiler_llvm_context::Context::new_evm(
                     &llvm,
                     self.path.as_str(),
@@ -101,7 +144,28 @@ impl Contract {
             }
         };
 
+        let metadata = {
+            let project = project.read().expect("Sync");
+            Metadata::new(
+                self.metadata.take(),
+                project.version.to_owned(),
+                project.is_optimized,
+                project.llvm_options.clone(),
+            )
+        };
         let factory_dependencies = self.drain_factory_dependencies();
+        let is_debug_info = {
+            let project = project.read().expect("Sync");
+            project.is_debug_info || project.is_source_map
+        };
+        let (debug_info, debug_locations) = if is_debug_info {
+            (
+                Some(DebugInfo::new(&llvm, context.module(), self.path.as_str())),
+                Some(self.source.debug_locations(self.path.as_str())),
+            )
+        } else {
+            (None, None)
+        };
 
         self.source.declare(&mut context).map_err(|error| {
             anyhow::anyhow!(
@@ -110,15 +174,51 @@ impl Contract {
                 error
             )
         })?;
-        self.source.into_llvm(&mut context).map_err(|error| {
-            anyhow::anyhow!(
-                "The contract `{}` LLVM IR generator definition pass error: {}",
+        let ethir_truncations = match self.source {
+            Source::EVM(ref source) => Some(source.assembly.ethir_truncations()),
+            Source::Yul(_) => None,
+        };
+        self.source
+            .into_llvm_debug(&mut context, debug_info.as_ref())
+            .map_err(|error| {
+                anyhow::anyhow!(
+                    "The contract `{}` LLVM IR generator definition pass error: {}",
+                    self.path,
+                    error
+                )
+            })?;
+        let warning_settings = project.read().expect("Sync").warning_settings.clone();
+        let warnings: Vec<SolcStandardJsonOutputError> = match ethir_truncations {
+            Some(truncations) if !warning_settings.is_suppressed(Warning::EthIRTruncation) => {
+                truncations
+                    .iter()
+                    .map(SolcStandardJsonOutputError::ethir_truncation)
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        if warning_settings.as_errors && !warnings.is_empty() {
+            anyhow::bail!(
+                "The contract `{}` warnings reported as errors:\n{}",
                 self.path,
-                error
-            )
-        })?;
+                warnings
+                    .iter()
+                    .map(|warning| warning.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+        }
+        if let Some(debug_info) = debug_info.as_ref() {
+            debug_info.finalize();
+        }
 
         let mut build = context.build(self.path.as_str())?;
+        let source_map = match debug_locations {
+            Some(debug_locations) => {
+                SourceMap::from_assembly_text(build.assembly_text.as_str(), &debug_locations)
+            }
+            None => SourceMap::default(),
+        };
         for dependency in factory_dependencies.into_iter() {
             let full_path = project
                 .read()
@@ -144,7 +244,10 @@ impl Contract {
             };
             build.factory_dependencies.insert(hash, full_path);
         }
-        Ok(build)
+        if let MetadataHash::Keccak256 = project.read().expect("Sync").metadata_hash {
+            metadata.append_hash(self.path.as_str(), &mut build)?;
+        }
+        Ok((build, source_map, metadata, warnings))
     }
 }
 
@@ -2,6 +2,8 @@
 //! The `solc --standard-json` contract EVM legacy assembly source.
 //!
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::evmla::assembly::Assembly;
 
 ///
@@ -33,6 +35,19 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        self.assembly.into_llvm(context)
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for EVM
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
+        self.assembly.into_llvm_debug(context, debug_info)
     }
 }
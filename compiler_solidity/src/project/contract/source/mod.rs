@@ -5,7 +5,11 @@
 pub mod evm;
 pub mod yul;
 
+use crate::compat_report::substitution::Substitution;
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::evmla::assembly::Assembly;
+use crate::source_map::debug_locations::DebugLocations;
 use crate::yul::parser::statement::object::Object;
 
 use self::evm::EVM;
@@ -38,6 +42,27 @@ impl Source {
     pub fn new_evm(assembly: Assembly) -> Self {
         Self::EVM(EVM::new(assembly))
     }
+
+    ///
+    /// Returns the EVM semantics substitutions made by the translator.
+    ///
+    pub fn compat_substitutions(&self) -> Vec<Substitution> {
+        match self {
+            Self::Yul(inner) => inner.compat_substitutions(),
+            Self::EVM(inner) => inner.assembly.compat_substitutions(),
+        }
+    }
+
+    ///
+    /// Returns the debug locations table, which maps the locations attached to the LLVM IR back
+    /// to the Solidity source code ranges.
+    ///
+    pub fn debug_locations(&self, path: &str) -> DebugLocations {
+        match self {
+            Self::Yul(inner) => inner.debug_locations(path),
+            Self::EVM(inner) => inner.assembly.debug_locations(),
+        }
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Source
@@ -52,9 +77,22 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Source
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         match self {
-            Self::Yul(inner) => inner.into_llvm(context),
-            Self::EVM(inner) => inner.into_llvm(context),
+            Self::Yul(inner) => inner.into_llvm_debug(context, debug_info),
+            Self::EVM(inner) => inner.into_llvm_debug(context, debug_info),
         }
     }
 }
@@ -2,6 +2,19 @@
 //! The `solc --standard-json` contract Yul source.
 //!
 
+use std::collections::BTreeMap;
+
+use crate::compat_report::substitution::Substitution;
+use crate::compat_report::yul_visitor::YulVisitor;
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
+use crate::source_map::debug_locations::DebugLocations;
+use crate::source_map::entry::Entry as SourceMapEntry;
+use crate::yul::lexer::token::lexeme::Lexeme;
+use crate::yul::lexer::token::solidity_location::SolidityLocation;
+use crate::yul::lexer::token::Token;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
 use crate::yul::parser::statement::object::Object;
 
 ///
@@ -22,6 +35,89 @@ impl Yul {
     pub fn new(source: String, object: Object) -> Self {
         Self { source, object }
     }
+
+    ///
+    /// Returns the EVM semantics substitutions made by the Yul translator.
+    ///
+    /// The built-in function calls are found in the AST, so the comments and string literals
+    /// are not matched.
+    ///
+    pub fn compat_substitutions(&self) -> Vec<Substitution> {
+        let solidity_locations = self.solidity_locations();
+        YulVisitor::substitutions(&self.object)
+            .into_iter()
+            .filter_map(|(location, name)| {
+                let (instruction, description) = match name {
+                    FunctionName::CodeSize => ("CODESIZE", Substitution::CODESIZE),
+                    FunctionName::CallCode => ("CALLCODE", Substitution::CALLCODE),
+                    _ => return None,
+                };
+                let solidity_location = solidity_locations
+                    .get(&(location.line, location.column))
+                    .and_then(|solidity| solidity.resolve(&self.object.source_paths));
+                let location = match solidity_location {
+                    Some(solidity_location) => {
+                        format!("{} (Yul {})", solidity_location, location)
+                    }
+                    None => format!("Yul {}", location),
+                };
+                Some(Substitution::new(
+                    instruction.to_owned(),
+                    description,
+                    location,
+                    false,
+                ))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the debug locations table, which maps the Yul lines and columns back to the
+    /// Solidity source code ranges set by the `@src` annotations.
+    ///
+    /// The Yul code is referred to as the `path` file.
+    ///
+    pub fn debug_locations(&self, path: &str) -> DebugLocations {
+        let mut debug_locations = DebugLocations::default();
+        for ((line, column), solidity) in self.solidity_locations().into_iter() {
+            debug_locations.insert(
+                path,
+                line,
+                column,
+                SourceMapEntry::new(
+                    solidity.start as usize,
+                    solidity.end.saturating_sub(solidity.start) as usize,
+                    solidity.source as usize,
+                ),
+            );
+        }
+        debug_locations
+    }
+
+    ///
+    /// Returns the Solidity source code locations of the Yul tokens by their lines and columns.
+    ///
+    fn solidity_locations(&self) -> BTreeMap<(usize, usize), SolidityLocation> {
+        let mut lexer = Lexer::new(self.source.to_owned());
+        let mut solidity_locations = BTreeMap::new();
+        loop {
+            match lexer.next() {
+                Ok(Token {
+                    lexeme: Lexeme::EndOfFile,
+                    ..
+                }) => break,
+                Ok(Token {
+                    location,
+                    solidity_location: Some(solidity_location),
+                    ..
+                }) => {
+                    solidity_locations.insert((location.line, location.column), solidity_location);
+                }
+                Ok(_) | Err(_) => {}
+            }
+        }
+        solidity_locations
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Yul
@@ -33,6 +129,55 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        self.object.into_llvm(context)
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Yul
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
+        self.object.into_llvm_debug(context, debug_info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::Yul;
+
+    fn yul(source: &str) -> Yul {
+        let mut lexer = Lexer::new(source.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Invalid test data");
+        Yul::new(source.to_owned(), object)
+    }
+
+    #[test]
+    fn compat_substitutions() {
+        let source = r#"
+object "Test" {
+    code {
+        {
+            // codesize() and callcode() in a comment
+            mstore(0, "codesize")
+            mstore(32, codesize())
+            return(0, 64)
+        }
+    }
+}
+    "#;
+
+        let substitutions = yul(source).compat_substitutions();
+        assert_eq!(substitutions.len(), 1);
+        assert_eq!(substitutions[0].instruction, "CODESIZE");
+        assert_eq!(substitutions[0].location, "Yul 7:24");
+        assert!(!substitutions[0].is_lenient_only);
     }
 }
@@ -5,15 +5,28 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::RwLock;
 
 use crate::build::contract::Contract as ContractBuild;
 use crate::build::Build;
+use crate::compat_report::CompatReport;
+use crate::diagnostic::collection::Collection as DiagnosticCollection;
+use crate::diagnostic::Diagnostic;
 use crate::dump_flag::DumpFlag;
+use crate::evmla::assembly::Assembly;
+use crate::evmla::ethereal_ir::function::cfg::format::Format as EthIRCFGFormat;
+use crate::metadata::hash::Hash as MetadataHash;
 use crate::project::contract::source::Source;
 use crate::project::contract::state::State;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
+use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+use crate::warning::settings::Settings as WarningSettings;
+use crate::yul::analyzer::Analyzer;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::object::Object;
 
@@ -33,6 +46,26 @@ pub struct Project {
     pub identifier_paths: BTreeMap<String, String>,
     /// The library addresses.
     pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// Whether the EVM legacy assembly unsupported instructions are translated leniently.
+    pub is_evmla_lenient: bool,
+    /// Whether the LLVM debug information is emitted.
+    pub is_debug_info: bool,
+    /// Whether the bytecode source maps are requested.
+    pub is_source_map: bool,
+    /// The maximum number of the Ethereal IR block clones per function, if limited.
+    pub ethir_clone_budget: Option<usize>,
+    /// The Ethereal IR control flow graph format and the directory it is written to, if dumped.
+    pub ethir_cfg_dump: Option<(EthIRCFGFormat, PathBuf)>,
+    /// Whether the Ethereal IR block truncations are errors instead of warnings.
+    pub is_ethir_strict: bool,
+    /// Whether the LLVM optimizer is enabled, as recorded in the contract metadata.
+    pub is_optimized: bool,
+    /// The LLVM options, as recorded in the contract metadata.
+    pub llvm_options: Vec<String>,
+    /// The contract metadata hash appended to the bytecode.
+    pub metadata_hash: MetadataHash,
+    /// The zkSync compatibility warning settings, applied to the warnings of the translation.
+    pub warning_settings: WarningSettings,
 }
 
 impl Project {
@@ -57,9 +90,96 @@ impl Project {
                 .collect(),
             identifier_paths,
             libraries,
+            is_evmla_lenient: false,
+            is_debug_info: false,
+            is_source_map: false,
+            ethir_clone_budget: None,
+            ethir_cfg_dump: None,
+            is_ethir_strict: false,
+            is_optimized: false,
+            llvm_options: Vec::new(),
+            metadata_hash: MetadataHash::None,
+            warning_settings: WarningSettings::default(),
         }
     }
 
+    ///
+    /// Sets whether the EVM legacy assembly unsupported instructions, e.g. `PC` or `CALLCODE`,
+    /// are translated with the EVM semantics substitutions instead of failing.
+    ///
+    pub fn set_evmla_lenient(&mut self, is_evmla_lenient: bool) {
+        self.is_evmla_lenient = is_evmla_lenient;
+    }
+
+    ///
+    /// Sets whether the LLVM debug information, that is, the compile unit of each contract and
+    /// the subprograms of its functions, is emitted.
+    ///
+    pub fn set_debug_info(&mut self, is_debug_info: bool) {
+        self.is_debug_info = is_debug_info;
+    }
+
+    ///
+    /// Sets whether the bytecode source maps are requested, which requires the LLVM debug
+    /// information to be emitted.
+    ///
+    pub fn set_source_map(&mut self, is_source_map: bool) {
+        self.is_source_map = is_source_map;
+    }
+
+    ///
+    /// Sets the maximum number of the Ethereal IR block clones per function, beyond which the
+    /// EVM legacy assembly contracts fail to compile.
+    ///
+    pub fn set_ethir_clone_budget(&mut self, ethir_clone_budget: Option<usize>) {
+        self.ethir_clone_budget = ethir_clone_budget;
+    }
+
+    ///
+    /// Sets the Ethereal IR control flow graph format and the directory the graph of each
+    /// EVM legacy assembly contract is written to.
+    ///
+    pub fn set_ethir_cfg_dump(&mut self, ethir_cfg_dump: Option<(EthIRCFGFormat, PathBuf)>) {
+        self.ethir_cfg_dump = ethir_cfg_dump;
+    }
+
+    ///
+    /// Sets whether the Ethereal IR blocks truncated as unreachable because of an invalid stack
+    /// state fail the compilation instead of being reported as warnings.
+    ///
+    pub fn set_ethir_strict(&mut self, is_ethir_strict: bool) {
+        self.is_ethir_strict = is_ethir_strict;
+    }
+
+    ///
+    /// Sets whether the LLVM optimizer is enabled, which is recorded in the contract metadata.
+    ///
+    pub fn set_optimized(&mut self, is_optimized: bool) {
+        self.is_optimized = is_optimized;
+    }
+
+    ///
+    /// Sets the LLVM options, which are recorded in the contract metadata.
+    ///
+    pub fn set_llvm_options(&mut self, llvm_options: Vec<String>) {
+        self.llvm_options = llvm_options;
+    }
+
+    ///
+    /// Sets the type of the contract metadata hash appended to the bytecode.
+    ///
+    pub fn set_metadata_hash(&mut self, metadata_hash: MetadataHash) {
+        self.metadata_hash = metadata_hash;
+    }
+
+    ///
+    /// Sets the zkSync compatibility warning settings, which suppress the warnings of the
+    /// translation, e.g. the Ethereal IR block truncations, or report them as errors.
+    ///
+    pub fn set_warning_settings(&mut self, warning_settings: WarningSettings) {
+        self.warning_settings = warning_settings;
+    }
+
     ///
     /// Compiles the specified contract, setting its build artifacts.
     ///
@@ -86,9 +206,16 @@ impl Project {
                 let identifier = contract.identifier().to_owned();
                 let abi = contract.abi.take();
                 match contract.compile(project.clone(), optimizer_settings, dump_flags) {
-                    Ok(build) => {
-                        let build =
-                            ContractBuild::new(contract_path.to_owned(), identifier, build, abi);
+                    Ok((build, source_map, metadata, warnings)) => {
+                        let build = ContractBuild::new(
+                            contract_path.to_owned(),
+                            identifier,
+                            build,
+                            source_map,
+                            abi,
+                            Some(metadata),
+                            warnings,
+                        );
                         project
                             .write()
                             .expect("Sync")
@@ -175,21 +302,36 @@ impl Project {
         Ok(build)
     }
 
+    ///
+    /// Returns the EVM compatibility report of all contracts, which are not compiled yet.
+    ///
+    pub fn compat_report(&self) -> CompatReport {
+        CompatReport::new(
+            self.contract_states
+                .iter()
+                .filter_map(|(path, state)| match state {
+                    State::Source(contract) => {
+                        Some((path.to_owned(), contract.source.compat_substitutions()))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+
     ///
     /// Parses the default Yul source code and returns the source data.
     ///
     pub fn try_from_default_yul(path: &Path, version: &semver::Version) -> anyhow::Result<Self> {
         let yul = std::fs::read_to_string(path)
             .map_err(|error| anyhow::anyhow!("Yul file {:?} reading error: {}", path, error))?;
-        let mut lexer = Lexer::new(yul.clone());
         let path = path.to_string_lossy().to_string();
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error,))?;
+        let object = Self::parse_yul(path.as_str(), yul.as_str())?;
 
         let mut project_contracts = BTreeMap::new();
         project_contracts.insert(
             path.clone(),
-            Contract::new(path, Source::new_yul(yul, object), None),
+            Contract::new(path, Source::new_yul(yul, object), None, None),
         );
         Ok(Self::new(
             version.to_owned(),
@@ -198,21 +340,140 @@ impl Project {
         ))
     }
 
+    ///
+    /// Reads the EVM legacy assembly JSON file and returns the source data.
+    ///
+    /// The file contains either a single contract assembly, named after the file, or a map of
+    /// the contract assemblies by their names or full paths. The `.data` dependencies, which are
+    /// not in the map, are added as separate contracts named after their hashes, so the
+    /// dependencies are resolved like the `solc` output ones.
+    ///
+    pub fn try_from_evmla(
+        path: &Path,
+        version: &semver::Version,
+        libraries: BTreeMap<String, BTreeMap<String, String>>,
+    ) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path).map_err(|error| {
+            anyhow::anyhow!(
+                "EVM legacy assembly file {:?} reading error: {}",
+                path,
+                error
+            )
+        })?;
+        Self::try_from_evmla_str(path, json.as_str(), version, libraries)
+    }
+
+    ///
+    /// Parses the EVM legacy assembly JSON read from the file at `path` and returns the source
+    /// data.
+    ///
+    /// The `path` is only used to name the contracts.
+    ///
+    pub fn try_from_evmla_str(
+        path: &Path,
+        json: &str,
+        version: &semver::Version,
+        libraries: BTreeMap<String, BTreeMap<String, String>>,
+    ) -> anyhow::Result<Self> {
+        let json: serde_json::Value = serde_json::from_str(json).map_err(|error| {
+            anyhow::anyhow!(
+                "EVM legacy assembly file {:?} parsing error: {}",
+                path,
+                error
+            )
+        })?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let path = path.to_string_lossy().to_string();
+
+        let mut assemblies: BTreeMap<String, Assembly> = if json.get(".code").is_some() {
+            let assembly = serde_json::from_value(json).map_err(|error| {
+                anyhow::anyhow!(
+                    "EVM legacy assembly file `{}` parsing error: {}",
+                    path,
+                    error
+                )
+            })?;
+            vec![(format!("{}:{}", path, name), assembly)]
+                .into_iter()
+                .collect()
+        } else {
+            serde_json::from_value::<BTreeMap<String, Assembly>>(json)
+                .map_err(|error| {
+                    anyhow::anyhow!(
+                        "EVM legacy assembly file `{}` parsing error: {}",
+                        path,
+                        error
+                    )
+                })?
+                .into_iter()
+                .map(|(name, assembly)| {
+                    if name.contains(':') {
+                        (name, assembly)
+                    } else {
+                        (format!("{}:{}", path, name), assembly)
+                    }
+                })
+                .collect()
+        };
+
+        let mut hashes: HashSet<String> = assemblies.values().map(Assembly::keccak256).collect();
+        let mut queue: Vec<Assembly> = assemblies.values().cloned().collect();
+        while let Some(assembly) = queue.pop() {
+            for dependency in assembly.dependencies().into_iter() {
+                let hash = dependency.keccak256();
+                if hashes.insert(hash.clone()) {
+                    assemblies.insert(format!("{}:{}", path, hash), dependency.to_owned());
+                    queue.push(dependency.to_owned());
+                }
+            }
+        }
+
+        let mut files: BTreeMap<String, BTreeMap<String, SolcStandardJsonOutputContract>> =
+            BTreeMap::new();
+        for (full_path, assembly) in assemblies.into_iter() {
+            let (file, name) = full_path.rsplit_once(':').expect("Always exists");
+            files
+                .entry(file.to_owned())
+                .or_insert_with(BTreeMap::new)
+                .insert(
+                    name.to_owned(),
+                    SolcStandardJsonOutputContract::new_assembly(assembly),
+                );
+        }
+        let mut output = SolcStandardJsonOutput {
+            contracts: Some(files),
+            sources: None,
+            errors: None,
+            version: None,
+            long_version: None,
+            zk_version: None,
+        };
+        output.try_to_project(
+            &BTreeMap::new(),
+            libraries,
+            SolcPipeline::EVM,
+            version,
+            &[],
+            &WarningSettings::default(),
+        )
+    }
+
     ///
     /// Parses the test Yul source code and returns the source data.
     ///
     /// Only for integration testing purposes.
     ///
     pub fn try_from_test_yul(yul: &str, version: &semver::Version) -> anyhow::Result<Self> {
-        let mut lexer = Lexer::new(yul.to_owned());
         let path = "Test".to_owned();
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error,))?;
+        let object = Self::parse_yul(path.as_str(), yul)?;
 
         let mut project_contracts = BTreeMap::new();
         project_contracts.insert(
             path.clone(),
-            Contract::new(path, Source::new_yul(yul.to_owned(), object), None),
+            Contract::new(path, Source::new_yul(yul.to_owned(), object), None, None),
         );
         Ok(Self::new(
             version.to_owned(),
@@ -220,6 +481,24 @@ impl Project {
             BTreeMap::new(),
         ))
     }
+
+    ///
+    /// Parses and analyzes the Yul source code, rendering all the errors found.
+    ///
+    fn parse_yul(path: &str, yul: &str) -> anyhow::Result<Object> {
+        let mut lexer = Lexer::new(yul.to_owned());
+        Object::parse_all(&mut lexer)
+            .and_then(|object| Analyzer::analyze(&object).map(|()| object))
+            .map_err(|errors| {
+                DiagnosticCollection::new(
+                    errors
+                        .iter()
+                        .map(|error| Diagnostic::new_yul(path, yul, error))
+                        .collect(),
+                )
+                .into()
+            })
+    }
 }
 
 impl compiler_llvm_context::Dependency for Project {
@@ -286,3 +565,106 @@ impl compiler_llvm_context::Dependency for Project {
         anyhow::bail!("Library `{}` not found in the project", path);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::evmla::assembly::data::Data;
+    use crate::evmla::assembly::Assembly;
+    use crate::project::contract::source::Source;
+    use crate::project::contract::state::State;
+
+    use super::Project;
+
+    const RUNTIME: &str = r#"{ ".code": [{ "name": "STOP" }] }"#;
+
+    const DEPENDENCY: &str = r#"{ ".code": [{ "name": "INVALID" }], ".data": { "0": { ".code": [{ "name": "INVALID" }] } } }"#;
+
+    fn deploy(data: &str) -> String {
+        format!(
+            r#"{{ ".code": [{{ "name": "PUSH", "value": "80" }}, {{ "name": "STOP" }}], ".data": {{ {} }} }}"#,
+            data
+        )
+    }
+
+    const PATH: &str = "contracts/Test.json";
+
+    fn project(json: &str) -> Project {
+        Project::try_from_evmla_str(
+            Path::new(PATH),
+            json,
+            &semver::Version::new(0, 8, 17),
+            Default::default(),
+        )
+        .expect("Invalid test data")
+    }
+
+    fn assembly<'a>(project: &'a Project, full_path: &str) -> &'a Assembly {
+        match project.contract_states.get(full_path) {
+            Some(State::Source(contract)) => match contract.source {
+                Source::EVM(ref evm) => &evm.assembly,
+                Source::Yul(_) => panic!("Contract `{}` is not EVM legacy assembly", full_path),
+            },
+            _ => panic!("Contract `{}` not found", full_path),
+        }
+    }
+
+    #[test]
+    fn evmla_single() {
+        let json = deploy(format!(r#""0": {}"#, RUNTIME).as_str());
+        let project = project(json.as_str());
+
+        let full_path = format!("{}:Test", PATH);
+        assert_eq!(
+            project.contract_states.keys().collect::<Vec<&String>>(),
+            vec![&full_path]
+        );
+        assert_eq!(
+            assembly(&project, full_path.as_str()).full_path.as_deref(),
+            Some(full_path.as_str())
+        );
+    }
+
+    #[test]
+    fn evmla_map() {
+        let contract = deploy(format!(r#""0": {}"#, RUNTIME).as_str());
+        let json = format!(
+            r#"{{ "A": {}, "contracts/B.sol:B": {} }}"#,
+            contract, contract
+        );
+        let project = project(json.as_str());
+
+        assert_eq!(
+            project
+                .contract_states
+                .keys()
+                .cloned()
+                .collect::<Vec<String>>(),
+            vec!["contracts/B.sol:B".to_owned(), format!("{}:A", PATH)]
+        );
+    }
+
+    #[test]
+    fn evmla_dependency_by_hash() {
+        let json = deploy(format!(r#""0": {}, "1": {}"#, RUNTIME, DEPENDENCY).as_str());
+        let project = project(json.as_str());
+
+        let hash = serde_json::from_str::<Assembly>(DEPENDENCY)
+            .expect("Invalid test data")
+            .keccak256();
+        let dependency_path = format!("{}:{}", PATH, hash);
+        assert!(project
+            .contract_states
+            .contains_key(dependency_path.as_str()));
+
+        let assembly = assembly(&project, format!("{}:Test", PATH).as_str());
+        assert!(assembly
+            .factory_dependencies
+            .contains(dependency_path.as_str()));
+        match assembly.data.as_ref().and_then(|data| data.get("1")) {
+            Some(Data::Path(path)) => assert_eq!(path, &dependency_path),
+            data => panic!("Dependency not resolved: {:?}", data),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//!
+//! The contract size report entry.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contract size report entry.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The bytecode size in bytes.
+    pub bytes: usize,
+    /// The bytecode size in words.
+    pub words: usize,
+    /// The number of words left until the bytecode size limit, negative if it is exceeded.
+    pub words_left: i64,
+    /// The number of factory dependencies.
+    pub factory_dependencies: usize,
+    /// The number of instructions in the assembly.
+    pub instructions: usize,
+    /// The number of constants in the assembly.
+    pub constants: usize,
+    /// The numbers of instructions by the function, if requested.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub functions: BTreeMap<String, usize>,
+}
+
+impl Contract {
+    ///
+    /// Collects the size statistics of the contract build.
+    ///
+    /// If `with_functions` is set, the instructions are attributed to the functions they are
+    /// placed in, that is, to the last global label of the code section.
+    ///
+    pub fn new(build: &compiler_llvm_context::Build, with_functions: bool) -> Self {
+        let words = build.bytecode.len() / compiler_common::SIZE_FIELD;
+        let (instructions, constants, mut functions) =
+            Self::count_assembly_text(build.assembly_text.as_str());
+        if !with_functions {
+            functions.clear();
+        }
+
+        Self {
+            bytes: build.bytecode.len(),
+            words,
+            words_left: crate::r#const::BYTECODE_MAX_WORDS as i64 - words as i64,
+            factory_dependencies: build.factory_dependencies.len(),
+            instructions,
+            constants,
+            functions,
+        }
+    }
+
+    ///
+    /// Counts the instructions, the constants, and the instructions by the function in the
+    /// zkEVM assembly text.
+    ///
+    fn count_assembly_text(assembly_text: &str) -> (usize, usize, BTreeMap<String, usize>) {
+        let mut instructions = 0;
+        let mut constants = 0;
+        let mut functions = BTreeMap::new();
+        let mut is_code = true;
+        let mut function: Option<&str> = None;
+
+        for line in assembly_text.lines() {
+            let line = line.split(';').next().unwrap_or_default().trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(label) = line.strip_suffix(':') {
+                if is_code && !label.starts_with('.') {
+                    function = Some(label);
+                }
+                continue;
+            }
+
+            if line.starts_with('.') {
+                let mut parts = line.split_whitespace();
+                match (parts.next(), parts.next()) {
+                    (Some(".text"), _) => is_code = true,
+                    (Some(".section"), Some(name)) => is_code = name.starts_with(".text"),
+                    (Some(".data"), _) | (Some(".bss"), _) => is_code = false,
+                    (Some(directive), _) if directive.starts_with(".rodata") => is_code = false,
+                    (Some(".cell"), _) => constants += 1,
+                    _ => {}
+                }
+                continue;
+            }
+
+            if is_code {
+                instructions += 1;
+                if let Some(function) = function {
+                    *functions.entry(function.to_owned()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        (instructions, constants, functions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Contract;
+
+    #[test]
+    fn count_assembly_text() {
+        let assembly_text = r#"
+	.text
+	.file	"Test.sol:Test"
+	.globl	__entry
+__entry:
+.func_begin0:
+	add	r1, r0, r3
+	sub.s!	1, r3, r2 ; the comment
+	near_call	r0, @fun_transfer_12, @DEFAULT_UNWIND
+.BB0_1:
+	ret
+fun_transfer_12:
+	add	@CPI0_0[0], r0, r1
+	ret
+	.rodata.cst32
+	.p2align	5
+CPI0_0:
+	.cell	42
+CPI0_1:
+	.cell	-1
+"#;
+
+        let (instructions, constants, functions) = Contract::count_assembly_text(assembly_text);
+        assert_eq!(instructions, 6);
+        assert_eq!(constants, 2);
+        assert_eq!(functions.get("__entry"), Some(&4));
+        assert_eq!(functions.get("fun_transfer_12"), Some(&2));
+        assert_eq!(functions.get("CPI0_0"), None);
+    }
+}
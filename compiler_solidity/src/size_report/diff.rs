@@ -0,0 +1,184 @@
+//!
+//! The contract size report diff.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::contract::Contract;
+use super::SizeReport;
+
+///
+/// The contract size report diff.
+///
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct Diff {
+    /// The contract changes by the contract path.
+    pub contracts: BTreeMap<String, Change>,
+}
+
+///
+/// The contract size change.
+///
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    /// The previous entry, if the contract existed.
+    pub previous: Option<Contract>,
+    /// The current entry, if the contract still exists.
+    pub current: Option<Contract>,
+    /// The bytecode size difference in bytes.
+    pub bytes: i64,
+    /// The bytecode size difference in words.
+    pub words: i64,
+    /// The number of instructions difference.
+    pub instructions: i64,
+    /// The number of constants difference.
+    pub constants: i64,
+}
+
+impl Diff {
+    ///
+    /// Compares the `current` report against the `previous` one.
+    ///
+    pub fn new(previous: &SizeReport, current: &SizeReport) -> Self {
+        let mut contracts = BTreeMap::new();
+        for path in previous.contracts.keys().chain(current.contracts.keys()) {
+            if contracts.contains_key(path) {
+                continue;
+            }
+            contracts.insert(
+                path.to_owned(),
+                Change::new(
+                    previous.contracts.get(path).cloned(),
+                    current.contracts.get(path).cloned(),
+                ),
+            );
+        }
+
+        Self { contracts }
+    }
+
+    ///
+    /// Returns the diff as a JSON string.
+    ///
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Always valid")
+    }
+}
+
+impl Change {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(previous: Option<Contract>, current: Option<Contract>) -> Self {
+        let delta = |field: fn(&Contract) -> usize| {
+            current.as_ref().map(field).unwrap_or_default() as i64
+                - previous.as_ref().map(field).unwrap_or_default() as i64
+        };
+
+        Self {
+            bytes: delta(|contract| contract.bytes),
+            words: delta(|contract| contract.words),
+            instructions: delta(|contract| contract.instructions),
+            constants: delta(|contract| contract.constants),
+            previous,
+            current,
+        }
+    }
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .contracts
+            .keys()
+            .map(String::len)
+            .chain(std::iter::once("Contract".len()))
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:width$}  {:>18}  {:>14}  {:>18}  {:>12}",
+            "Contract",
+            "Bytes",
+            "Words",
+            "Instructions",
+            "Constants",
+            width = width,
+        )?;
+        for (path, change) in self.contracts.iter() {
+            let current = match (change.previous.as_ref(), change.current.as_ref()) {
+                (_, Some(current)) => current,
+                (Some(_), None) => {
+                    writeln!(f, "{:width$}  removed", path, width = width)?;
+                    continue;
+                }
+                (None, None) => continue,
+            };
+            let cell = |value: usize, delta: i64| {
+                if change.previous.is_some() {
+                    format!("{} ({:+})", value, delta)
+                } else {
+                    format!("{} (new)", value)
+                }
+            };
+
+            writeln!(
+                f,
+                "{:width$}  {:>18}  {:>14}  {:>18}  {:>12}",
+                path,
+                cell(current.bytes, change.bytes),
+                cell(current.words, change.words),
+                cell(current.instructions, change.instructions),
+                cell(current.constants, change.constants),
+                width = width,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::super::contract::Contract;
+    use super::super::SizeReport;
+
+    fn contract(words: usize, instructions: usize) -> Contract {
+        Contract {
+            bytes: words * compiler_common::SIZE_FIELD,
+            words,
+            words_left: (crate::r#const::BYTECODE_MAX_WORDS - words) as i64,
+            factory_dependencies: 0,
+            instructions,
+            constants: 1,
+            functions: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let previous = SizeReport::new(BTreeMap::from([
+            ("A.sol:A".to_owned(), contract(5, 16)),
+            ("B.sol:B".to_owned(), contract(3, 8)),
+        ]));
+        let current = SizeReport::new(BTreeMap::from([
+            ("A.sol:A".to_owned(), contract(7, 24)),
+            ("C.sol:C".to_owned(), contract(3, 8)),
+        ]));
+
+        let diff = current.diff(&previous);
+        let a = &diff.contracts["A.sol:A"];
+        assert_eq!(a.bytes, 2 * compiler_common::SIZE_FIELD as i64);
+        assert_eq!(a.words, 2);
+        assert_eq!(a.instructions, 8);
+        assert!(diff.contracts["B.sol:B"].current.is_none());
+        assert!(diff.contracts["C.sol:C"].previous.is_none());
+        assert_eq!(diff.contracts["C.sol:C"].words, 3);
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The size report output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The size report output format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The human-readable table.
+    Table,
+    /// The JSON object, which can be diffed against in the later runs.
+    JSON,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::JSON),
+            value => anyhow::bail!(
+                "Invalid size report format `{}`. Available formats: table, json",
+                value
+            ),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//!
+//! The contract size report.
+//!
+
+pub mod contract;
+pub mod diff;
+pub mod format;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use self::contract::Contract;
+use self::diff::Diff;
+
+///
+/// The contract size report.
+///
+/// Lists the bytecode size, the distance to the size limit, and the code statistics of each
+/// contract, so the contracts approaching the limit can be found without deploying them.
+///
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SizeReport {
+    /// The contract entries by the contract path.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl SizeReport {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(contracts: BTreeMap<String, Contract>) -> Self {
+        Self { contracts }
+    }
+
+    ///
+    /// Reads the size report from the JSON file written by a previous run.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path).map_err(|error| {
+            anyhow::anyhow!("Size report file {:?} reading error: {}", path, error)
+        })?;
+        serde_json::from_str(json.as_str()).map_err(|error| {
+            anyhow::anyhow!("Size report file {:?} parsing error: {}", path, error)
+        })
+    }
+
+    ///
+    /// Compares the report against the `previous` one.
+    ///
+    pub fn diff(&self, previous: &Self) -> Diff {
+        Diff::new(previous, self)
+    }
+
+    ///
+    /// Returns the report as a JSON string.
+    ///
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Always valid")
+    }
+}
+
+impl std::fmt::Display for SizeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .contracts
+            .keys()
+            .map(String::len)
+            .chain(std::iter::once("Contract".len()))
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:width$}  {:>8}  {:>6}  {:>10}  {:>12}  {:>12}  {:>9}",
+            "Contract",
+            "Bytes",
+            "Words",
+            "Words left",
+            "Factory deps",
+            "Instructions",
+            "Constants",
+            width = width,
+        )?;
+        for (path, contract) in self.contracts.iter() {
+            writeln!(
+                f,
+                "{:width$}  {:>8}  {:>6}  {:>10}  {:>12}  {:>12}  {:>9}",
+                path,
+                contract.bytes,
+                contract.words,
+                contract.words_left,
+                contract.factory_dependencies,
+                contract.instructions,
+                contract.constants,
+                width = width,
+            )?;
+
+            let mut functions: Vec<(&String, &usize)> = contract.functions.iter().collect();
+            functions.sort_by(|(_, a), (_, b)| b.cmp(a));
+            for (name, instructions) in functions.into_iter() {
+                writeln!(
+                    f,
+                    "    {:width$}  {:>8} instructions  {:>5.1}%",
+                    name,
+                    instructions,
+                    (*instructions as f64) * 100.0 / (contract.instructions.max(1) as f64),
+                    width = width.saturating_sub(4),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
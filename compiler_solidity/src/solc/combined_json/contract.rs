@@ -19,12 +19,21 @@ pub struct Contract {
     /// The `solc` ABI output.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub abi: Option<serde_json::Value>,
+    /// The `solc` metadata output, extended by `zksolc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
     /// The `solc` hexadecimal binary output.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bin: Option<String>,
     /// The `solc` hexadecimal binary runtime part output.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bin_runtime: Option<String>,
+    /// The `solc` source map output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srcmap: Option<String>,
+    /// The `solc` source map runtime part output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srcmap_runtime: Option<String>,
     /// The factory dependencies.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub factory_deps: Option<BTreeMap<String, String>>,
@@ -19,7 +19,7 @@ use self::source::Source;
 ///
 /// The `solc --standard-json` input representation.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Input {
     /// The input language.
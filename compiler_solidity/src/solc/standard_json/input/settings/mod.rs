@@ -18,7 +18,7 @@ use self::selection::Selection;
 ///
 /// The `solc --standard-json` input settings representation.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
     /// The linker library addresses.
@@ -28,6 +28,11 @@ pub struct Settings {
     pub output_selection: serde_json::Value,
     /// The optimizer settings.
     pub optimizer: Optimizer,
+    /// The suppressed zkSync compatibility warning codes or names.
+    ///
+    /// Is not passed to `solc`, which rejects unknown settings.
+    #[serde(default, skip_serializing)]
+    pub suppressed_warnings: Option<Vec<String>>,
 }
 
 impl Settings {
@@ -43,6 +48,7 @@ impl Settings {
             libraries: Some(libraries),
             output_selection,
             optimizer: Optimizer::new(optimize),
+            suppressed_warnings: None,
         }
     }
 
@@ -60,6 +66,7 @@ impl Settings {
         let general_selections = vec![Selection::AST];
         let per_contract_selections = vec![
             Selection::ABI,
+            Selection::Metadata,
             match pipeline {
                 SolcPipeline::Yul => Selection::Yul,
                 SolcPipeline::EVM => Selection::EVM,
@@ -81,6 +88,27 @@ impl Settings {
         serde_json::Value::Object(map)
     }
 
+    ///
+    /// Whether the bytecode source map is requested by the `output_selection` filters.
+    ///
+    pub fn is_source_map_requested(output_selection: &serde_json::Value) -> bool {
+        output_selection
+            .as_object()
+            .into_iter()
+            .flat_map(|files| files.values())
+            .filter_map(serde_json::Value::as_object)
+            .flat_map(|contracts| contracts.values())
+            .filter_map(serde_json::Value::as_array)
+            .flatten()
+            .filter_map(serde_json::Value::as_str)
+            .any(|selection| {
+                matches!(
+                    selection,
+                    "*" | "evm" | "evm.bytecode" | "evm.bytecode.sourceMap"
+                )
+            })
+    }
+
     ///
     /// Generates the AST output selection pattern.
     ///
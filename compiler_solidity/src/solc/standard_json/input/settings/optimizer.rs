@@ -8,7 +8,7 @@ use serde::Serialize;
 ///
 /// The `solc --standard-json` input settings optimizer representation.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Optimizer {
     /// Whether the optimizer is enabled.
@@ -24,6 +24,9 @@ pub enum Selection {
     /// The EVM legacy assembly JSON representation.
     #[serde(rename = "evm.legacyAssembly")]
     EVM,
+    /// The contract metadata JSON string.
+    #[serde(rename = "metadata")]
+    Metadata,
 }
 
 impl std::fmt::Display for Selection {
@@ -33,6 +36,7 @@ impl std::fmt::Display for Selection {
             Self::AST => write!(f, "ast"),
             Self::Yul => write!(f, "irOptimized"),
             Self::EVM => write!(f, "evm.legacyAssembly"),
+            Self::Metadata => write!(f, "metadata"),
         }
     }
 }
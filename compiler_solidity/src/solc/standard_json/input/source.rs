@@ -11,7 +11,7 @@ use serde::Serialize;
 ///
 /// The `solc --standard-json` input source representation.
 ///
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Source {
     /// The source code file content.
@@ -12,13 +12,16 @@ use serde::Serialize;
 pub struct Bytecode {
     /// The bytecode object.
     pub object: String,
+    /// The bytecode source map in the `solc` compressed format.
+    #[serde(rename = "sourceMap", skip_serializing_if = "Option::is_none")]
+    pub source_map: Option<String>,
 }
 
 impl Bytecode {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(object: String) -> Self {
-        Self { object }
+    pub fn new(object: String, source_map: Option<String>) -> Self {
+        Self { object, source_map }
     }
 }
@@ -25,13 +25,23 @@ pub struct EVM {
 }
 
 impl EVM {
+    ///
+    /// A shortcut constructor for the EVM legacy assembly.
+    ///
+    pub fn new_assembly(assembly: Assembly) -> Self {
+        Self {
+            assembly: Some(assembly),
+            bytecode: None,
+        }
+    }
+
     ///
     /// A shortcut constructor for the zkEVM bytecode.
     ///
-    pub fn new_zkevm_bytecode(bytecode: String) -> Self {
+    pub fn new_zkevm_bytecode(bytecode: String, source_map: Option<String>) -> Self {
         Self {
             assembly: None,
-            bytecode: Some(Bytecode::new(bytecode)),
+            bytecode: Some(Bytecode::new(bytecode, source_map)),
         }
     }
 }
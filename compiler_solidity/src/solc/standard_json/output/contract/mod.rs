@@ -9,6 +9,8 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::evmla::assembly::Assembly;
+
 use self::evm::EVM;
 
 ///
@@ -23,6 +25,9 @@ pub struct Contract {
     /// The contract ABI representation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub abi: Option<serde_json::Value>,
+    /// The contract metadata JSON string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
     /// Contract's bytecode and related objects
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub evm: Option<EVM>,
@@ -33,3 +38,19 @@ pub struct Contract {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
 }
+
+impl Contract {
+    ///
+    /// A shortcut constructor for the contract with the EVM legacy assembly only.
+    ///
+    pub fn new_assembly(assembly: Assembly) -> Self {
+        Self {
+            ir_optimized: None,
+            abi: None,
+            metadata: None,
+            evm: Some(EVM::new_assembly(assembly)),
+            factory_dependencies: None,
+            hash: None,
+        }
+    }
+}
@@ -9,6 +9,11 @@ use std::str::FromStr;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::diagnostic::Diagnostic;
+use crate::evmla::ethereal_ir::function::truncation::Truncation;
+use crate::warning::Warning;
+use crate::yul::error::Error as YulError;
+
 use self::source_location::SourceLocation;
 
 ///
@@ -35,23 +40,14 @@ pub struct Error {
 
 impl Error {
     ///
-    /// Returns the `ecrecover` usage warning.
+    /// Returns the zkSync compatibility warning from the registry.
     ///
-    pub fn warning_ecrecover(src: Option<&str>) -> Self {
-        let message = r#"
-┌─────────────────────────────────────────────────────────────────────────────────────────┐
-│ Warning: It seems like you are using ecrecover to validate signature of a user account. │
-│ zkSync 2.0 will come with native account abstraction support. It is highly recommended  │
-│ NOT to rely on the fact that the account has ECDSA private key attached to it, since    │
-│ they may be ruled by a multisig and use other signature scheme. You can read more about │
-│ how you can get ready for the future AA launch here:                                    │
-│ https://v2-docs.zksync.io/dev/zksync-v2/aa.html#important-account-abstraction-support   │
-└─────────────────────────────────────────────────────────────────────────────────────────┘"#
-            .to_owned();
+    pub fn warning(warning: Warning, src: Option<&str>) -> Self {
+        let message = warning.message().to_owned();
 
         Self {
             component: "general".to_owned(),
-            error_code: None,
+            error_code: Some(warning.code().to_owned()),
             formatted_message: message.clone(),
             message,
             severity: "warning".to_owned(),
@@ -61,20 +57,14 @@ impl Error {
     }
 
     ///
-    /// Returns the `extcodesize` usage warning.
+    /// Returns the warning about the unknown code in the warning suppression marker at the byte
+    /// offset `range`, which is ignored.
     ///
-    pub fn warning_extcodesize(src: Option<&str>) -> Self {
-        let message = r#"
-┌─────────────────────────────────────────────────────────────────────────────────────────┐
-│ Warning: It seems like your code or one of its dependencies uses extcodesize. This      │
-│ opcode is most often used for the following:                                            │
-│     - to detect whether an address belongs to smart contracts                           │
-│     - to detect whether the deploy code execution has ended                             │
-│ zkSync 2.0 will support account abstraction by default (so every account will be a      │
-│ smart contract, even private-key controlled EOAs). So none of the use-cases above will  │
-│ work. Please do your best to avoid differentiating between contracts and non-contracts. │
-└─────────────────────────────────────────────────────────────────────────────────────────┘"#
-            .to_owned();
+    pub fn warning_unknown_code(code: &str, range: &std::ops::Range<usize>) -> Self {
+        let message = format!(
+            "Unknown warning `{}` in the suppression marker is ignored",
+            code
+        );
 
         Self {
             component: "general".to_owned(),
@@ -82,17 +72,72 @@ impl Error {
             formatted_message: message.clone(),
             message,
             severity: "warning".to_owned(),
-            source_location: src.map(SourceLocation::from_str).and_then(Result::ok),
+            source_location: Some(SourceLocation {
+                file: String::new(),
+                start: range.start as isize,
+                end: range.end as isize,
+            }),
             r#type: "Warning".to_owned(),
         }
     }
 
     ///
-    /// Appends the contract path to the message..
+    /// Returns the zkSync compatibility warning, if the message is one.
     ///
-    pub fn push_contract_path(&mut self, path: &str) {
-        self.formatted_message
-            .push_str(format!("\n--> {}\n", path).as_str());
+    pub fn as_registry_warning(&self) -> Option<Warning> {
+        Warning::ALL
+            .iter()
+            .copied()
+            .find(|warning| self.error_code.as_deref() == Some(warning.code()))
+    }
+
+    ///
+    /// Returns the Ethereal IR block truncation warning, which is registered with a generic
+    /// message, since the block and reason are specific to each truncation.
+    ///
+    pub fn ethir_truncation(truncation: &Truncation) -> Self {
+        let message = truncation.to_string();
+
+        Self {
+            component: "general".to_owned(),
+            error_code: Some(Warning::EthIRTruncation.code().to_owned()),
+            formatted_message: message.clone(),
+            message,
+            severity: "warning".to_owned(),
+            source_location: truncation.location.clone(),
+            r#type: "Warning".to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the Yul parsing or semantic analysis error.
+    ///
+    pub fn error_yul(path: &str, source_code: &str, error: &YulError) -> Self {
+        let message = error.to_string();
+        let r#type = match error {
+            YulError::Lexer(_) | YulError::Parser(_) => "ParserError",
+            YulError::Analyzer(_) => "DeclarationError",
+        };
+
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: Diagnostic::new_yul(path, source_code, error).to_string(),
+            message,
+            severity: "error".to_owned(),
+            source_location: None,
+            r#type: r#type.to_owned(),
+        }
+    }
+
+    ///
+    /// Sets the source code file path, and renders the message with the source code snippet.
+    ///
+    pub fn push_source_code(&mut self, path: &str, source_code: Option<&str>) {
+        self.source_location
+            .get_or_insert_with(|| SourceLocation::new(path.to_owned()))
+            .file = path.to_owned();
+        self.formatted_message = Diagnostic::new_solc(self, source_code).to_string();
     }
 }
 
@@ -101,3 +146,37 @@ impl std::fmt::Display for Error {
         write!(f, "{}", self.formatted_message)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::evmla::assembly::instruction::name::Name as InstructionName;
+    use crate::evmla::ethereal_ir::function::truncation::Truncation;
+    use crate::warning::Warning;
+
+    use super::Error;
+
+    #[test]
+    fn ethir_truncation_registered() {
+        let truncation = Truncation::new(
+            compiler_llvm_context::FunctionBlockKey::new(
+                compiler_llvm_context::CodeType::Runtime,
+                num::BigUint::from(42u64),
+            ),
+            3,
+            InstructionName::JUMP,
+            "Stack underflow".to_owned(),
+            None,
+        );
+
+        let warning = Error::ethir_truncation(&truncation);
+        assert_eq!(warning.error_code.as_deref(), Some("ZK1010"));
+        assert_eq!(
+            warning.as_registry_warning(),
+            Some(Warning::EthIRTruncation)
+        );
+        assert_eq!(
+            "ethir-truncation".parse::<Warning>().ok(),
+            Some(Warning::EthIRTruncation)
+        );
+    }
+}
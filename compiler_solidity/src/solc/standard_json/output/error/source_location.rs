@@ -21,6 +21,21 @@ pub struct SourceLocation {
     pub end: isize,
 }
 
+impl SourceLocation {
+    ///
+    /// A shortcut constructor for a location with unknown offsets.
+    ///
+    /// The unknown offsets are denoted by `-1`, like in `solc`.
+    ///
+    pub fn new(file: String) -> Self {
+        Self {
+            file,
+            start: -1,
+            end: -1,
+        }
+    }
+}
+
 impl FromStr for SourceLocation {
     type Err = anyhow::Error;
 
@@ -45,3 +60,9 @@ impl FromStr for SourceLocation {
         })
     }
 }
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}..{}", self.file, self.start, self.end)
+    }
+}
@@ -18,11 +18,16 @@ use crate::project::contract::source::Source as ProjectContractSource;
 use crate::project::contract::Contract as ProjectContract;
 use crate::project::Project;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::source_map::line_index::LineIndex;
+use crate::warning::marker::Markers as WarningMarkers;
+use crate::warning::settings::Settings as WarningSettings;
+use crate::yul::analyzer::Analyzer;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::Contract;
 use self::error::Error as SolcStandardJsonOutputError;
+use self::source::ast::check as ast_check;
 use self::source::Source;
 
 ///
@@ -56,16 +61,28 @@ impl Output {
     ///
     pub fn try_to_project(
         &mut self,
+        source_code_files: &BTreeMap<String, String>,
         libraries: BTreeMap<String, BTreeMap<String, String>>,
         pipeline: SolcPipeline,
         version: &semver::Version,
         dump_flags: &[DumpFlag],
+        warning_settings: &WarningSettings,
     ) -> anyhow::Result<Project> {
-        self.preprocess_ast()?;
+        self.preprocess_ast(source_code_files, warning_settings)?;
         if let SolcPipeline::EVM = pipeline {
             self.preprocess_dependencies()?;
         }
-        self.sources = None;
+        let source_paths: BTreeMap<usize, String> = self
+            .sources
+            .take()
+            .into_iter()
+            .flatten()
+            .map(|(path, source)| (source.id, path))
+            .collect();
+        let line_indexes: BTreeMap<String, LineIndex> = source_code_files
+            .iter()
+            .map(|(path, source_code)| (path.to_owned(), LineIndex::new(source_code.as_str())))
+            .collect();
 
         let files = match self.contracts.as_mut() {
             Some(files) => files,
@@ -80,6 +97,7 @@ impl Output {
             }
         };
         let mut project_contracts = BTreeMap::new();
+        let mut yul_errors = Vec::new();
 
         for (path, contracts) in files.iter_mut() {
             for (name, contract) in contracts.iter_mut() {
@@ -101,29 +119,56 @@ impl Output {
                         }
 
                         let mut lexer = Lexer::new(ir_optimized.clone());
-                        let object = Object::parse(&mut lexer, None).map_err(|error| {
-                            anyhow::anyhow!("Contract `{}` parsing error: {:?}", full_path, error)
-                        })?;
+                        let object = match Object::parse_all(&mut lexer)
+                            .and_then(|object| Analyzer::analyze(&object).map(|()| object))
+                        {
+                            Ok(object) => object,
+                            Err(errors) => {
+                                yul_errors.extend(errors.iter().map(|error| {
+                                    SolcStandardJsonOutputError::error_yul(
+                                        full_path.as_str(),
+                                        ir_optimized.as_str(),
+                                        error,
+                                    )
+                                }));
+                                continue;
+                            }
+                        };
 
                         ProjectContractSource::new_yul(ir_optimized, object)
                     }
                     SolcPipeline::EVM => {
-                        let assembly =
+                        let mut assembly =
                             match contract.evm.as_ref().and_then(|evm| evm.assembly.as_ref()) {
                                 Some(assembly) => assembly.to_owned(),
                                 None => continue,
                             };
+                        assembly.resolve_source_locations(&source_paths, &line_indexes);
 
                         ProjectContractSource::new_evm(assembly)
                     }
                 };
 
-                let project_contract =
-                    ProjectContract::new(full_path.clone(), source, contract.abi.take());
+                let project_contract = ProjectContract::new(
+                    full_path.clone(),
+                    source,
+                    contract.abi.take(),
+                    contract.metadata.take(),
+                );
                 project_contracts.insert(full_path, project_contract);
             }
         }
 
+        if !yul_errors.is_empty() {
+            let message = yul_errors
+                .iter()
+                .map(|error| error.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+            self.errors.get_or_insert_with(Vec::new).extend(yul_errors);
+            anyhow::bail!("{}", message);
+        }
+
         Ok(Project::new(
             version.to_owned(),
             project_contracts,
@@ -217,7 +262,13 @@ impl Output {
     ///
     /// Traverses the AST and returns the list of additional errors and warnings.
     ///
-    fn preprocess_ast(&mut self) -> anyhow::Result<()> {
+    /// The warnings suppressed by `warning_settings` or by the source code markers are skipped.
+    ///
+    fn preprocess_ast(
+        &mut self,
+        source_code_files: &BTreeMap<String, String>,
+        warning_settings: &WarningSettings,
+    ) -> anyhow::Result<()> {
         let sources = match self.sources.as_ref() {
             Some(sources) => sources,
             None => return Ok(()),
@@ -226,14 +277,51 @@ impl Output {
         let mut messages = Vec::new();
         for (path, source) in sources.iter() {
             if let Some(ast) = source.ast.as_ref() {
-                let mut warnings = ast.get_warnings()?;
+                let source_code = source_code_files.get(path.as_str()).map(String::as_str);
+                let markers = source_code
+                    .map(WarningMarkers::from_source_code)
+                    .unwrap_or_default();
+
+                let mut warnings = ast_check::run_all(ast);
+                warnings.extend(markers.unknown_codes.iter().map(|(code, range)| {
+                    SolcStandardJsonOutputError::warning_unknown_code(code.as_str(), range)
+                }));
+                warnings.retain(|warning| {
+                    let registry_warning = match warning.as_registry_warning() {
+                        Some(registry_warning) => registry_warning,
+                        None => return true,
+                    };
+                    let offset = warning
+                        .source_location
+                        .as_ref()
+                        .and_then(|location| usize::try_from(location.start).ok());
+                    !warning_settings.is_suppressed(registry_warning)
+                        && !offset
+                            .map(|offset| markers.is_suppressed(registry_warning, offset))
+                            .unwrap_or_default()
+                });
                 for warning in warnings.iter_mut() {
-                    warning.push_contract_path(path.as_str());
+                    if warning_settings.as_errors {
+                        warning.severity = "error".to_owned();
+                    }
+                    warning.push_source_code(path.as_str(), source_code);
                 }
                 messages.extend(warnings);
             }
         }
 
+        let promoted = if warning_settings.as_errors && !messages.is_empty() {
+            Some(
+                messages
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )
+        } else {
+            None
+        };
+
         self.errors = match self.errors.take() {
             Some(mut errors) => {
                 errors.extend(messages);
@@ -242,6 +330,10 @@ impl Output {
             None => Some(messages),
         };
 
+        if let Some(message) = promoted {
+            anyhow::bail!("{}", message);
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,40 @@
+//!
+//! The inline assembly `codesize` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The inline assembly `codesize` usage check.
+///
+/// The instruction is mostly used to detect whether the constructor is being executed.
+///
+#[derive(Debug, Default)]
+pub struct CodeSize {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for CodeSize {
+    fn visit_ast(&mut self, ast: &AST) {
+        if ast.as_yul_function_call() == Some("codesize") {
+            self.warnings.push(SolcStandardJsonOutputError::warning(
+                Warning::CodeSize,
+                ast.src.as_deref(),
+            ));
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for CodeSize {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
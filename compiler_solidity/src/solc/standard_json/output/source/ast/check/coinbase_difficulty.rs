@@ -0,0 +1,40 @@
+//!
+//! The `block.coinbase` and `block.difficulty` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The `block.coinbase` and `block.difficulty` usage check.
+///
+#[derive(Debug, Default)]
+pub struct CoinbaseDifficulty {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for CoinbaseDifficulty {
+    fn visit_ast(&mut self, ast: &AST) {
+        if let Some((expression, "coinbase" | "difficulty")) = ast.as_member_access() {
+            if expression.is_identifier("block") {
+                self.warnings.push(SolcStandardJsonOutputError::warning(
+                    Warning::CoinbaseDifficulty,
+                    ast.src.as_deref(),
+                ));
+            }
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for CoinbaseDifficulty {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The `ecrecover` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The `ecrecover` usage check.
+///
+#[derive(Debug, Default)]
+pub struct EcRecover {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for EcRecover {
+    fn visit_ast(&mut self, ast: &AST) {
+        if ast
+            .as_function_call()
+            .map(|callee| callee.is_identifier("ecrecover"))
+            .unwrap_or_default()
+        {
+            self.warnings.push(SolcStandardJsonOutputError::warning(
+                Warning::EcRecover,
+                ast.src.as_deref(),
+            ));
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for EcRecover {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The `extcodesize` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The `extcodesize` usage check.
+///
+#[derive(Debug, Default)]
+pub struct ExtCodeSize {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for ExtCodeSize {
+    fn visit_ast(&mut self, ast: &AST) {
+        if ast.as_yul_function_call() == Some("extcodesize") {
+            self.warnings.push(SolcStandardJsonOutputError::warning(
+                Warning::ExtCodeSize,
+                ast.src.as_deref(),
+            ));
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for ExtCodeSize {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
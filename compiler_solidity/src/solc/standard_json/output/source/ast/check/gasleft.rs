@@ -0,0 +1,42 @@
+//!
+//! The `gasleft` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The `gasleft` usage check.
+///
+#[derive(Debug, Default)]
+pub struct GasLeft {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for GasLeft {
+    fn visit_ast(&mut self, ast: &AST) {
+        if ast
+            .as_function_call()
+            .map(|callee| callee.is_identifier("gasleft"))
+            .unwrap_or_default()
+        {
+            self.warnings.push(SolcStandardJsonOutputError::warning(
+                Warning::GasLeft,
+                ast.src.as_deref(),
+            ));
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for GasLeft {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
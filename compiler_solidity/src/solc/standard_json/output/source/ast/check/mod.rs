@@ -0,0 +1,245 @@
+//!
+//! The `solc --standard-json` AST zkSync compatibility checks.
+//!
+
+pub mod codesize;
+pub mod coinbase_difficulty;
+pub mod ecrecover;
+pub mod extcodesize;
+pub mod gasleft;
+pub mod selfdestruct;
+pub mod transfer_send;
+pub mod tx_origin;
+pub mod unsupported_assembly;
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+
+use self::codesize::CodeSize;
+use self::coinbase_difficulty::CoinbaseDifficulty;
+use self::ecrecover::EcRecover;
+use self::extcodesize::ExtCodeSize;
+use self::gasleft::GasLeft;
+use self::selfdestruct::SelfDestruct;
+use self::transfer_send::TransferSend;
+use self::tx_origin::TxOrigin;
+use self::unsupported_assembly::UnsupportedAssembly;
+
+///
+/// The AST check, which is a visitor collecting the warnings.
+///
+pub trait Check: AstVisitor {
+    ///
+    /// Returns the warnings found during the traversal.
+    ///
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError>;
+}
+
+///
+/// Returns the registry of all checks.
+///
+/// A new check only has to be added here to be run on every source code file.
+///
+pub fn all() -> Vec<Box<dyn Check>> {
+    vec![
+        Box::new(EcRecover::default()),
+        Box::new(ExtCodeSize::default()),
+        Box::new(TransferSend::default()),
+        Box::new(TxOrigin::default()),
+        Box::new(CoinbaseDifficulty::default()),
+        Box::new(GasLeft::default()),
+        Box::new(SelfDestruct::default()),
+        Box::new(UnsupportedAssembly::default()),
+        Box::new(CodeSize::default()),
+    ]
+}
+
+///
+/// Runs all the registered checks on the AST and returns the warnings found.
+///
+pub fn run_all(ast: &AST) -> Vec<SolcStandardJsonOutputError> {
+    let mut warnings = Vec::new();
+    for mut check in all().into_iter() {
+        check.visit_ast(ast);
+        warnings.extend(check.take_warnings());
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solc::standard_json::output::source::ast::AST;
+    use crate::warning::Warning;
+
+    fn run_all(ast: serde_json::Value) -> Vec<Option<String>> {
+        let ast: AST = serde_json::from_value(ast).expect("Invalid test data");
+        super::run_all(&ast)
+            .into_iter()
+            .map(|warning| warning.error_code)
+            .collect()
+    }
+
+    #[test]
+    fn ecrecover() {
+        let ast = serde_json::json!({
+            "nodeType": "SourceUnit",
+            "nodes": [{
+                "nodeType": "FunctionDefinition",
+                "body": {
+                    "nodeType": "Block",
+                    "statements": [{
+                        "nodeType": "Return",
+                        "expression": {
+                            "nodeType": "FunctionCall",
+                            "src": "100:25:0",
+                            "expression": { "nodeType": "Identifier", "name": "ecrecover" },
+                            "arguments": [{ "nodeType": "Identifier", "name": "hash" }]
+                        }
+                    }]
+                }
+            }]
+        });
+
+        assert_eq!(
+            run_all(ast),
+            vec![Some(Warning::EcRecover.code().to_owned())]
+        );
+    }
+
+    #[test]
+    fn extcodesize() {
+        let ast = serde_json::json!({
+            "nodeType": "InlineAssembly",
+            "AST": {
+                "nodeType": "YulBlock",
+                "statements": [{
+                    "nodeType": "YulAssignment",
+                    "value": {
+                        "nodeType": "YulFunctionCall",
+                        "src": "200:20:0",
+                        "functionName": { "nodeType": "YulIdentifier", "name": "extcodesize" },
+                        "arguments": [{ "nodeType": "YulIdentifier", "name": "account" }]
+                    }
+                }]
+            }
+        });
+
+        assert_eq!(
+            run_all(ast),
+            vec![Some(Warning::ExtCodeSize.code().to_owned())]
+        );
+    }
+
+    #[test]
+    fn transfer_send() {
+        let ast = serde_json::json!({
+            "nodeType": "Block",
+            "statements": [{
+                "nodeType": "ExpressionStatement",
+                "expression": {
+                    "nodeType": "FunctionCall",
+                    "src": "300:30:0",
+                    "expression": {
+                        "nodeType": "MemberAccess",
+                        "memberName": "transfer",
+                        "expression": {
+                            "nodeType": "Identifier",
+                            "name": "recipient",
+                            "typeDescriptions": { "typeString": "address payable" }
+                        }
+                    }
+                }
+            }, {
+                "nodeType": "ExpressionStatement",
+                "expression": {
+                    "nodeType": "FunctionCall",
+                    "src": "340:30:0",
+                    "expression": {
+                        "nodeType": "MemberAccess",
+                        "memberName": "transfer",
+                        "expression": {
+                            "nodeType": "Identifier",
+                            "name": "token",
+                            "typeDescriptions": { "typeString": "contract IERC20" }
+                        }
+                    }
+                }
+            }]
+        });
+
+        assert_eq!(
+            run_all(ast),
+            vec![Some(Warning::TransferSend.code().to_owned())]
+        );
+    }
+
+    #[test]
+    fn tx_origin_and_block() {
+        let ast = serde_json::json!({
+            "nodeType": "BinaryOperation",
+            "leftExpression": {
+                "nodeType": "MemberAccess",
+                "memberName": "origin",
+                "expression": { "nodeType": "Identifier", "name": "tx" }
+            },
+            "rightExpression": {
+                "nodeType": "MemberAccess",
+                "memberName": "coinbase",
+                "expression": { "nodeType": "Identifier", "name": "block" }
+            }
+        });
+
+        assert_eq!(
+            run_all(ast),
+            vec![
+                Some(Warning::TxOrigin.code().to_owned()),
+                Some(Warning::CoinbaseDifficulty.code().to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn inline_assembly() {
+        let ast = serde_json::json!({
+            "nodeType": "InlineAssembly",
+            "AST": {
+                "nodeType": "YulBlock",
+                "statements": [
+                    {
+                        "nodeType": "YulFunctionCall",
+                        "functionName": { "nodeType": "YulIdentifier", "name": "pc" }
+                    },
+                    {
+                        "nodeType": "YulFunctionCall",
+                        "functionName": { "nodeType": "YulIdentifier", "name": "codesize" }
+                    },
+                    {
+                        "nodeType": "YulFunctionCall",
+                        "functionName": { "nodeType": "YulIdentifier", "name": "selfdestruct" }
+                    }
+                ]
+            }
+        });
+
+        assert_eq!(
+            run_all(ast),
+            vec![
+                Some(Warning::SelfDestruct.code().to_owned()),
+                Some(Warning::UnsupportedAssembly.code().to_owned()),
+                Some(Warning::CodeSize.code().to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ok() {
+        let ast = serde_json::json!({
+            "nodeType": "FunctionCall",
+            "expression": { "nodeType": "Identifier", "name": "keccak256" },
+            "arguments": [{ "nodeType": "Literal", "value": "ecrecover" }, 42]
+        });
+
+        assert!(run_all(ast).is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+//!
+//! The `selfdestruct` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The `selfdestruct` usage check.
+///
+/// Both the Solidity function and the inline assembly instruction are matched.
+///
+#[derive(Debug, Default)]
+pub struct SelfDestruct {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for SelfDestruct {
+    fn visit_ast(&mut self, ast: &AST) {
+        let is_function_call = ast
+            .as_function_call()
+            .map(|callee| callee.is_identifier("selfdestruct") || callee.is_identifier("suicide"))
+            .unwrap_or_default();
+        if is_function_call || ast.as_yul_function_call() == Some("selfdestruct") {
+            self.warnings.push(SolcStandardJsonOutputError::warning(
+                Warning::SelfDestruct,
+                ast.src.as_deref(),
+            ));
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for SelfDestruct {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
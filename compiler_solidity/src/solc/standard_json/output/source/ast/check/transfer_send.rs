@@ -0,0 +1,50 @@
+//!
+//! The address `transfer` and `send` usage check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The address `transfer` and `send` usage check.
+///
+/// The token methods with the same names are not matched, as the accessed expression type
+/// must be an address.
+///
+#[derive(Debug, Default)]
+pub struct TransferSend {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for TransferSend {
+    fn visit_ast(&mut self, ast: &AST) {
+        if let Some((expression, member_name)) =
+            ast.as_function_call().and_then(AST::as_member_access)
+        {
+            let is_address = expression
+                .type_descriptions
+                .as_ref()
+                .map(|type_descriptions| type_descriptions.is_address())
+                .unwrap_or_default();
+            if is_address && matches!(member_name, "transfer" | "send") {
+                self.warnings.push(SolcStandardJsonOutputError::warning(
+                    Warning::TransferSend,
+                    ast.src.as_deref(),
+                ));
+            }
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for TransferSend {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
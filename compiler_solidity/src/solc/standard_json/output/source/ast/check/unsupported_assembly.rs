@@ -0,0 +1,40 @@
+//!
+//! The unsupported inline assembly instructions check.
+//!
+
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::solc::standard_json::output::source::ast::check::Check;
+use crate::solc::standard_json::output::source::ast::visitor::walk_ast;
+use crate::solc::standard_json::output::source::ast::visitor::AstVisitor;
+use crate::solc::standard_json::output::source::ast::AST;
+use crate::warning::Warning;
+
+///
+/// The unsupported inline assembly instructions check.
+///
+/// Matches the `pc`, `extcodecopy`, and `callcode` instructions.
+///
+#[derive(Debug, Default)]
+pub struct UnsupportedAssembly {
+    /// The warnings found.
+    warnings: Vec<SolcStandardJsonOutputError>,
+}
+
+impl AstVisitor for UnsupportedAssembly {
+    fn visit_ast(&mut self, ast: &AST) {
+        if let Some("pc" | "extcodecopy" | "callcode") = ast.as_yul_function_call() {
+            self.warnings.push(SolcStandardJsonOutputError::warning(
+                Warning::UnsupportedAssembly,
+                ast.src.as_deref(),
+            ));
+        }
+
+        walk_ast(self, ast);
+    }
+}
+
+impl Check for UnsupportedAssembly {
+    fn take_warnings(&mut self) -> Vec<SolcStandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+}
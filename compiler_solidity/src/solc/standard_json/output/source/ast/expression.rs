@@ -5,7 +5,6 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 use crate::solc::standard_json::output::source::AST as SolcStandardJsonOutputSourceAST;
 
 ///
@@ -21,36 +20,6 @@ pub enum Expression {
 }
 
 impl Expression {
-    ///
-    /// Checks the AST node for `ecrecover`.
-    ///
-    pub fn check_ecrecover(&self) -> Option<SolcStandardJsonOutputError> {
-        match self {
-            Self::Node(inner) => inner.check_ecrecover(),
-            Self::Other(_) => None,
-        }
-    }
-
-    ///
-    /// Checks the AST node for `extcodesize`.
-    ///
-    pub fn check_extcodesize(&self) -> Option<SolcStandardJsonOutputError> {
-        match self {
-            Self::Node(inner) => inner.check_extcodesize(),
-            Self::Other(_) => None,
-        }
-    }
-
-    ///
-    /// Returns the list of warnings for some specific parts of the AST.
-    ///
-    pub fn get_warnings(&self) -> anyhow::Result<Vec<SolcStandardJsonOutputError>> {
-        match self {
-            Self::Node(inner) => inner.get_warnings(),
-            Self::Other(_) => Ok(vec![]),
-        }
-    }
-
     ///
     /// If the expression is a node, returns the reference.
     ///
@@ -2,14 +2,16 @@
 //! The `solc --standard-json` AST output.
 //!
 
+pub mod check;
 pub mod expression;
+pub mod type_descriptions;
+pub mod visitor;
 
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
-
 use self::expression::Expression;
+use self::type_descriptions::TypeDescriptions;
 
 ///
 /// The `solc --standard-json` AST output.
@@ -26,6 +28,10 @@ pub struct AST {
     pub src: Option<String>,
     /// The function name.
     pub function_name: Option<Box<Self>>,
+    /// The accessed member name.
+    pub member_name: Option<String>,
+    /// The expression type descriptions.
+    pub type_descriptions: Option<TypeDescriptions>,
 
     /// The inner AST.
     #[serde(rename = "AST")]
@@ -100,187 +106,43 @@ pub struct AST {
 
 impl AST {
     ///
-    /// Checks the AST node for `ecrecover`.
+    /// Checks whether the node is an identifier with the specified name.
     ///
-    pub fn check_ecrecover(&self) -> Option<SolcStandardJsonOutputError> {
-        if let Some(node_type) = self.node_type.as_ref() {
-            if node_type.as_str() != "FunctionCall" {
-                return None;
-            }
-        }
-
-        let expression = self.expression.as_ref()?.as_node()?;
-        if let Some(node_type) = expression.node_type.as_ref() {
-            if node_type.as_str() != "Identifier" {
-                return None;
-            }
-        }
-        if let Some(name) = expression.name.as_ref() {
-            if name.as_str() != "ecrecover" {
-                return None;
-            }
-        }
-
-        Some(SolcStandardJsonOutputError::warning_ecrecover(
-            self.src.as_deref(),
-        ))
+    pub fn is_identifier(&self, name: &str) -> bool {
+        self.node_type.as_deref() == Some("Identifier") && self.name.as_deref() == Some(name)
     }
+
     ///
-    /// Checks the AST node for `extcodesize`.
+    /// If the node is a function call, returns the called expression node.
     ///
-    pub fn check_extcodesize(&self) -> Option<SolcStandardJsonOutputError> {
-        if let Some(node_type) = self.node_type.as_ref() {
-            if node_type.as_str() != "YulFunctionCall" {
-                return None;
-            }
+    pub fn as_function_call(&self) -> Option<&Self> {
+        if self.node_type.as_deref() != Some("FunctionCall") {
+            return None;
         }
+        self.expression.as_ref()?.as_node()
+    }
 
-        if let Some(function_name) = self
-            .function_name
-            .as_ref()
-            .and_then(|inner| inner.name.as_ref())
-        {
-            if function_name.as_str() != "extcodesize" {
-                return None;
-            }
+    ///
+    /// If the node is a member access, returns the accessed expression node and the member name.
+    ///
+    pub fn as_member_access(&self) -> Option<(&Self, &str)> {
+        if self.node_type.as_deref() != Some("MemberAccess") {
+            return None;
         }
-
-        Some(SolcStandardJsonOutputError::warning_extcodesize(
-            self.src.as_deref(),
+        Some((
+            self.expression.as_ref()?.as_node()?,
+            self.member_name.as_deref()?,
         ))
     }
 
     ///
-    /// Returns the list of warnings for some specific parts of the AST.
+    /// If the node is a Yul function call, returns the function name.
     ///
-    pub fn get_warnings(&self) -> anyhow::Result<Vec<SolcStandardJsonOutputError>> {
-        let mut warnings = Vec::new();
-        if let Some(warning) = self.check_ecrecover() {
-            warnings.push(warning);
+    pub fn as_yul_function_call(&self) -> Option<&str> {
+        if self.node_type.as_deref() != Some("YulFunctionCall") {
+            return None;
         }
-        if let Some(warning) = self.check_extcodesize() {
-            warnings.push(warning);
-        }
-
-        if let Some(inner) = self.ast.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.nodes.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-        if let Some(inner) = self.statements.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-
-        if let Some(inner) = self.arguments.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-        if let Some(inner) = self.declarations.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-        if let Some(inner) = self.members.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-        if let Some(inner) = self.components.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-        if let Some(inner) = self.clauses.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-        if let Some(inner) = self.options.as_ref() {
-            for element in inner.iter() {
-                warnings.extend(element.get_warnings()?);
-            }
-        }
-
-        if let Some(inner) = self.body.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.true_body.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.false_body.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.condition.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.initialization_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.left_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.right_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.sub_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.true_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.false_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.loop_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.base_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.index_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.start_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.end_expression.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.value.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.initial_value.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.external_call.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.event_call.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.error_call.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.left_hand_side.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.right_hand_side.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-        if let Some(inner) = self.length.as_ref() {
-            warnings.extend(inner.get_warnings()?);
-        }
-
-        Ok(warnings)
+        self.function_name.as_ref()?.name.as_deref()
     }
 
     ///
@@ -0,0 +1,30 @@
+//!
+//! The `solc --standard-json` AST expression type descriptions.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The `solc --standard-json` AST expression type descriptions.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeDescriptions {
+    /// The type identifier, e.g. `t_address_payable`.
+    pub type_identifier: Option<String>,
+    /// The type string, e.g. `address payable`.
+    pub type_string: Option<String>,
+}
+
+impl TypeDescriptions {
+    ///
+    /// Checks whether the type is an address, payable or not.
+    ///
+    pub fn is_address(&self) -> bool {
+        matches!(
+            self.type_string.as_deref(),
+            Some("address") | Some("address payable")
+        )
+    }
+}
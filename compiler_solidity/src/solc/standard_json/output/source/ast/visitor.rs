@@ -0,0 +1,99 @@
+//!
+//! The `solc --standard-json` AST visitor.
+//!
+
+use crate::solc::standard_json::output::source::ast::expression::Expression;
+use crate::solc::standard_json::output::source::ast::AST;
+
+///
+/// The `solc --standard-json` AST visitor.
+///
+/// The default implementations walk all the child nodes, so an implementor only overrides the
+/// methods it is interested in, and calls the corresponding `walk_*` function to go deeper.
+///
+pub trait AstVisitor {
+    ///
+    /// Visits the AST node.
+    ///
+    fn visit_ast(&mut self, ast: &AST) {
+        walk_ast(self, ast);
+    }
+
+    ///
+    /// Visits the AST expression.
+    ///
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+}
+
+///
+/// Visits all the child nodes and expressions of the AST node.
+///
+pub fn walk_ast<V: AstVisitor + ?Sized>(visitor: &mut V, ast: &AST) {
+    for inner in [&ast.function_name, &ast.ast].into_iter().flatten() {
+        visitor.visit_ast(inner);
+    }
+    for inner in [&ast.nodes, &ast.statements].into_iter().flatten() {
+        for element in inner.iter() {
+            visitor.visit_ast(element);
+        }
+    }
+
+    for inner in [
+        &ast.arguments,
+        &ast.declarations,
+        &ast.members,
+        &ast.components,
+        &ast.clauses,
+        &ast.options,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        for element in inner.iter() {
+            visitor.visit_expression(element);
+        }
+    }
+
+    for inner in [
+        &ast.body,
+        &ast.true_body,
+        &ast.false_body,
+        &ast.expression,
+        &ast.condition,
+        &ast.initialization_expression,
+        &ast.left_expression,
+        &ast.right_expression,
+        &ast.sub_expression,
+        &ast.true_expression,
+        &ast.false_expression,
+        &ast.loop_expression,
+        &ast.base_expression,
+        &ast.index_expression,
+        &ast.start_expression,
+        &ast.end_expression,
+        &ast.value,
+        &ast.initial_value,
+        &ast.external_call,
+        &ast.event_call,
+        &ast.error_call,
+        &ast.left_hand_side,
+        &ast.right_hand_side,
+        &ast.length,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        visitor.visit_expression(inner);
+    }
+}
+
+///
+/// Visits the expression node, if the expression is one.
+///
+pub fn walk_expression<V: AstVisitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    if let Some(inner) = expression.as_node() {
+        visitor.visit_ast(inner);
+    }
+}
@@ -0,0 +1,53 @@
+//!
+//! The debug locations table.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::source_map::entry::Entry;
+
+///
+/// The debug locations table, which maps the locations attached to the LLVM IR, that is, the
+/// file, line, and column, back to the Solidity source code ranges.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DebugLocations {
+    /// The Solidity source code ranges by the debug file, line, and column.
+    locations: BTreeMap<(String, usize, usize), Entry>,
+}
+
+impl DebugLocations {
+    ///
+    /// Inserts the location, keeping the first range inserted for the same position.
+    ///
+    pub fn insert(&mut self, file: &str, line: usize, column: usize, entry: Entry) {
+        self.locations
+            .entry((file.to_owned(), line, column))
+            .or_insert(entry);
+    }
+
+    ///
+    /// Returns the Solidity source code range of the position.
+    ///
+    /// If there is no range at the exact column, e.g. if the backend has dropped the columns,
+    /// the first range of the line is returned.
+    ///
+    pub fn get(&self, file: &str, line: usize, column: usize) -> Option<Entry> {
+        let key = (file.to_owned(), line, column);
+        if let Some(entry) = self.locations.get(&key) {
+            return Some(*entry);
+        }
+
+        self.locations
+            .range((file.to_owned(), line, 0)..=(file.to_owned(), line, usize::MAX))
+            .next()
+            .map(|(_, entry)| *entry)
+    }
+
+    ///
+    /// Whether the table is empty.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+}
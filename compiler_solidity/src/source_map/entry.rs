@@ -0,0 +1,43 @@
+//!
+//! The source map entry.
+//!
+
+///
+/// The source map entry, which is a Solidity source code range in the `solc` terms.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// The start byte offset.
+    pub start: usize,
+    /// The length in bytes.
+    pub length: usize,
+    /// The source code file index in the `solc` output `sources` list.
+    pub source: usize,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: usize, length: usize, source: usize) -> Self {
+        Self {
+            start,
+            length,
+            source,
+        }
+    }
+
+    ///
+    /// Returns the `start`, `length`, and `source` fields, where the unknown location is `-1`.
+    ///
+    pub fn fields(entry: Option<Self>) -> [isize; 3] {
+        match entry {
+            Some(entry) => [
+                entry.start as isize,
+                entry.length as isize,
+                entry.source as isize,
+            ],
+            None => [-1; 3],
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//!
+//! The source code line index.
+//!
+
+///
+/// The source code line index, which converts byte offsets to lines and columns.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// The byte offsets of the line starts.
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(source_code: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source_code.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self { line_starts }
+    }
+
+    ///
+    /// Returns the line and column of the byte `offset`, both starting from 1.
+    ///
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+
+        (line + 1, offset - self.line_starts[line] + 1)
+    }
+}
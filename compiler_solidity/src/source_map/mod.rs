@@ -0,0 +1,189 @@
+//!
+//! The bytecode source map.
+//!
+
+pub mod debug_locations;
+pub mod entry;
+pub mod line_index;
+
+use std::collections::BTreeMap;
+
+use self::debug_locations::DebugLocations;
+use self::entry::Entry;
+
+///
+/// The bytecode source map, which maps each zkEVM instruction to a Solidity source code range.
+///
+/// The map is built from the line table the LLVM backend emits as the `.file` and `.loc`
+/// assembly directives, so the debug information is emitted if the source map is requested.
+///
+/// The map is displayed in the `solc` compressed `start:length:source` format, where the fields
+/// equal to the ones of the previous entry are omitted.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    /// The Solidity source code ranges of the instructions, in the bytecode order.
+    pub entries: Vec<Option<Entry>>,
+}
+
+impl SourceMap {
+    ///
+    /// Builds the source map from the zkEVM assembly text, resolving its line table with the
+    /// `debug_locations` of the contract.
+    ///
+    pub fn from_assembly_text(assembly_text: &str, debug_locations: &DebugLocations) -> Self {
+        let mut files = BTreeMap::new();
+        let mut current = None;
+        let mut entries = Vec::new();
+
+        for line in assembly_text.lines() {
+            let line = line.trim();
+
+            if let Some(arguments) = Self::strip_directive(line, ".file") {
+                let mut arguments = arguments.splitn(2, char::is_whitespace);
+                if let (Some(Ok(index)), Some(paths)) = (
+                    arguments.next().map(|index| index.parse::<usize>()),
+                    arguments.next(),
+                ) {
+                    let paths: Vec<&str> = paths.split('"').skip(1).step_by(2).collect();
+                    let path = match paths.as_slice() {
+                        [directory, name] if !directory.is_empty() && !name.starts_with('/') => {
+                            format!("{}/{}", directory, name)
+                        }
+                        [.., name] => name.to_string(),
+                        [] => continue,
+                    };
+                    files.insert(index, path);
+                }
+                continue;
+            }
+            if let Some(arguments) = Self::strip_directive(line, ".loc") {
+                let mut arguments = arguments
+                    .split_whitespace()
+                    .map(|argument| argument.parse::<usize>().ok());
+                current = match (arguments.next(), arguments.next(), arguments.next()) {
+                    (Some(Some(file)), Some(Some(line)), column) if line > 0 => {
+                        files.get(&file).and_then(|file| {
+                            debug_locations.get(file, line, column.flatten().unwrap_or_default())
+                        })
+                    }
+                    _ => None,
+                };
+                continue;
+            }
+            if line.starts_with('.') {
+                continue;
+            }
+
+            let line = line.split(';').next().unwrap_or_default().trim();
+            if line.is_empty() || line.starts_with('#') || line.ends_with(':') {
+                continue;
+            }
+            entries.push(current);
+        }
+
+        Self { entries }
+    }
+
+    ///
+    /// Whether at least one instruction has a known Solidity source code range.
+    ///
+    pub fn has_locations(&self) -> bool {
+        self.entries.iter().any(Option::is_some)
+    }
+
+    ///
+    /// Returns the directive arguments, if the line is the `directive`.
+    ///
+    fn strip_directive<'a>(line: &'a str, directive: &str) -> Option<&'a str> {
+        line.strip_prefix(directive)
+            .filter(|arguments| arguments.starts_with(char::is_whitespace))
+            .map(str::trim)
+    }
+}
+
+impl std::fmt::Display for SourceMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut previous = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                write!(f, ";")?;
+            }
+
+            let fields = Entry::fields(*entry);
+            let mut parts: Vec<String> = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| match previous {
+                    Some(previous) if Entry::fields(previous)[index] == *field => String::new(),
+                    _ => field.to_string(),
+                })
+                .collect();
+            while matches!(parts.last(), Some(part) if part.is_empty()) {
+                parts.pop();
+            }
+            write!(f, "{}", parts.join(":"))?;
+
+            previous = Some(*entry);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source_map::debug_locations::DebugLocations;
+    use crate::source_map::entry::Entry;
+    use crate::source_map::line_index::LineIndex;
+    use crate::source_map::SourceMap;
+
+    #[test]
+    fn compressed() {
+        let source_map = SourceMap {
+            entries: vec![
+                Some(Entry::new(0, 10, 0)),
+                Some(Entry::new(0, 10, 0)),
+                Some(Entry::new(5, 2, 0)),
+                None,
+                Some(Entry::new(5, 2, 1)),
+            ],
+        };
+
+        assert_eq!(source_map.to_string(), "0:10:0;;5:2;-1:-1:-1;5:2:1");
+    }
+
+    #[test]
+    fn from_assembly_text() {
+        let assembly_text = r#"
+	.text
+	.file	"Test.sol:Test"
+	.file	1 "" "Test.sol"
+	.globl	__entry
+__entry:
+.func_begin0:
+	.loc	1 3 9 prologue_end
+	add	r1, r0, r3
+	sub.s!	1, r3, r2 ; the comment
+	.loc	1 4 1
+	ret
+	.loc	1 0 0
+	jump	@.BB0_1
+"#;
+        let mut debug_locations = DebugLocations::default();
+        debug_locations.insert("Test.sol", 3, 9, Entry::new(40, 12, 0));
+        debug_locations.insert("Test.sol", 4, 5, Entry::new(60, 1, 0));
+
+        let source_map = SourceMap::from_assembly_text(assembly_text, &debug_locations);
+        assert_eq!(source_map.to_string(), "40:12:0;;60:1;-1:-1:-1");
+    }
+
+    #[test]
+    fn line_index() {
+        let line_index = LineIndex::new("contract Test {\n    uint x;\n}\n");
+
+        assert_eq!(line_index.line_column(0), (1, 1));
+        assert_eq!(line_index.line_column(20), (2, 5));
+        assert_eq!(line_index.line_column(28), (3, 1));
+    }
+}
@@ -0,0 +1,406 @@
+//!
+//! The contract bytecode verification.
+//!
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use crate::build::info::Info as BuildInfo;
+use crate::metadata::hash::Hash as MetadataHash;
+use crate::project::contract::state::State as ContractState;
+use crate::project::Project;
+use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+use crate::solc::Compiler as SolcCompiler;
+use crate::warning::settings::Settings as WarningSettings;
+
+///
+/// The contract bytecode verification.
+///
+/// Compares the supplied bytecode with the one recompiled from the build info, and if they
+/// differ, finds the settings which most likely caused the mismatch.
+///
+#[derive(Debug)]
+pub struct Verification {
+    /// The contract full path.
+    pub contract: String,
+    /// The supplied bytecode.
+    pub expected: Vec<u8>,
+    /// The recompiled bytecode.
+    pub actual: Vec<u8>,
+    /// The recompiled bytecode hash.
+    pub actual_hash: String,
+    /// The most likely causes of the mismatch.
+    pub causes: Vec<String>,
+}
+
+impl Verification {
+    ///
+    /// Recompiles the contract `path:Name` from the build info and compares it with the supplied
+    /// bytecode.
+    ///
+    /// The import paths are passed to `solc`, since they are not a part of the standard JSON input.
+    ///
+    pub fn try_from_build_info(
+        build_info: &BuildInfo,
+        solc: &SolcCompiler,
+        contract: &str,
+        expected: Vec<u8>,
+        base_path: Option<String>,
+        include_paths: Vec<String>,
+        allow_paths: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let solc_version = solc.version()?;
+        let solc_output = solc.standard_json(
+            build_info.input.clone(),
+            base_path,
+            include_paths,
+            allow_paths,
+        )?;
+        if let Some(error) = solc_output
+            .errors
+            .iter()
+            .flatten()
+            .find(|error| error.severity.as_str() == "error")
+        {
+            anyhow::bail!("{}", error);
+        }
+
+        let (contract, build) = Self::compile(
+            build_info,
+            &solc_output,
+            contract,
+            build_info.is_optimized(),
+            build_info.metadata_hash,
+        )?;
+        let mut verification = Self {
+            contract,
+            expected,
+            actual: build.bytecode,
+            actual_hash: build.hash,
+            causes: Vec::new(),
+        };
+        if verification.is_verified() {
+            return Ok(verification);
+        }
+
+        if solc_version.long != build_info.solc_long_version {
+            verification.causes.push(format!(
+                "The `solc` version is `{}` instead of the recorded `{}`",
+                solc_version.long, build_info.solc_long_version
+            ));
+        }
+        let zk_version = semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
+        if zk_version != build_info.zk_version {
+            verification.causes.push(format!(
+                "The `zksolc` version is `{}` instead of the recorded `{}`",
+                zk_version, build_info.zk_version
+            ));
+        }
+        for (file, contracts) in build_info.libraries.iter() {
+            for (name, address) in contracts.iter() {
+                let address = match hex::decode(address.trim_start_matches("0x")) {
+                    Ok(address) => address,
+                    Err(_) => continue,
+                };
+                if Self::contains(verification.actual.as_slice(), address.as_slice())
+                    && !Self::contains(verification.expected.as_slice(), address.as_slice())
+                {
+                    verification.causes.push(format!(
+                        "The library `{}:{}` address is not the recorded 0x{}",
+                        file,
+                        name,
+                        hex::encode(address)
+                    ));
+                }
+            }
+        }
+
+        let other_metadata_hash = match build_info.metadata_hash {
+            MetadataHash::None => MetadataHash::Keccak256,
+            MetadataHash::Keccak256 => MetadataHash::None,
+        };
+        for (is_optimized, metadata_hash) in [
+            (!build_info.is_optimized(), build_info.metadata_hash),
+            (build_info.is_optimized(), other_metadata_hash),
+            (!build_info.is_optimized(), other_metadata_hash),
+        ] {
+            let (_contract, build) = Self::compile(
+                build_info,
+                &solc_output,
+                verification.contract.as_str(),
+                is_optimized,
+                metadata_hash,
+            )?;
+            if build.bytecode != verification.expected {
+                continue;
+            }
+
+            if is_optimized != build_info.is_optimized() {
+                verification.causes.push(format!(
+                    "The optimizer mode is `{}` instead of the recorded `{}`",
+                    if is_optimized { "cycles" } else { "none" },
+                    build_info.optimizer_mode
+                ));
+            }
+            if metadata_hash != build_info.metadata_hash {
+                verification.causes.push(format!(
+                    "The metadata hash type is `{}` instead of the recorded `{}`",
+                    metadata_hash, build_info.metadata_hash
+                ));
+            }
+            break;
+        }
+
+        if verification.causes.is_empty()
+            && verification.expected.len() == verification.actual.len()
+            && verification.first_different_word() == Some(verification.words() - 1)
+            && Self::metadata_suffix(verification.expected.as_slice()).is_some()
+        {
+            verification.causes.push(
+                "Only the metadata hash differs, that is, the `solc` metadata, e.g. the source code comments, or the LLVM options"
+                    .to_owned(),
+            );
+        }
+
+        Ok(verification)
+    }
+
+    ///
+    /// Whether the supplied bytecode is the same as the recompiled one.
+    ///
+    pub fn is_verified(&self) -> bool {
+        self.expected == self.actual
+    }
+
+    ///
+    /// Returns the index of the first word which differs between the supplied and recompiled
+    /// bytecode, if any.
+    ///
+    pub fn first_different_word(&self) -> Option<usize> {
+        self.expected
+            .chunks(compiler_common::SIZE_FIELD)
+            .zip(self.actual.chunks(compiler_common::SIZE_FIELD))
+            .position(|(expected, actual)| expected != actual)
+            .or_else(|| {
+                if self.expected.len() != self.actual.len() {
+                    Some(self.words())
+                } else {
+                    None
+                }
+            })
+    }
+
+    ///
+    /// Returns the smaller number of words of the supplied and recompiled bytecode.
+    ///
+    fn words(&self) -> usize {
+        self.expected.len().min(self.actual.len()) / compiler_common::SIZE_FIELD
+    }
+
+    ///
+    /// Returns the metadata hash appended to the bytecode, that is, the last word preceded by the
+    /// zero word, if it looks like one.
+    ///
+    fn metadata_suffix(bytecode: &[u8]) -> Option<&[u8]> {
+        let words: Vec<&[u8]> = bytecode.chunks(compiler_common::SIZE_FIELD).collect();
+        match words.as_slice() {
+            [.., padding, hash] if words.len() >= 3 && padding.iter().all(|byte| *byte == 0) => {
+                Some(*hash)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Recompiles the contract with the specified optimizer mode and metadata hash type,
+    /// returning its full path and build.
+    ///
+    fn compile(
+        build_info: &BuildInfo,
+        solc_output: &SolcStandardJsonOutput,
+        contract: &str,
+        is_optimized: bool,
+        metadata_hash: MetadataHash,
+    ) -> anyhow::Result<(String, compiler_llvm_context::Build)> {
+        let source_code_files: BTreeMap<String, String> = build_info
+            .input
+            .sources
+            .iter()
+            .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+            .collect();
+        let mut project = solc_output.clone().try_to_project(
+            &source_code_files,
+            build_info.libraries.clone(),
+            build_info.pipeline,
+            &build_info.solc_version,
+            &[],
+            &WarningSettings::default(),
+        )?;
+        project.set_optimized(is_optimized);
+        project.set_llvm_options(build_info.llvm_options.clone());
+        project.set_metadata_hash(metadata_hash);
+
+        let suffix = format!("/{}", contract);
+        let mut paths = project
+            .contract_states
+            .keys()
+            .filter(|path| path.as_str() == contract || path.ends_with(suffix.as_str()));
+        let path = match (paths.next(), paths.next()) {
+            (Some(path), None) => path.to_owned(),
+            (Some(_), Some(_)) => {
+                anyhow::bail!("Contract `{}` is ambiguous in the build info", contract)
+            }
+            (None, _) => anyhow::bail!("Contract `{}` not found in the build info", contract),
+        };
+
+        let optimizer_settings = if is_optimized {
+            compiler_llvm_context::OptimizerSettings::cycles()
+        } else {
+            compiler_llvm_context::OptimizerSettings::none()
+        };
+        let project = Arc::new(RwLock::new(project));
+        Project::compile(project.clone(), path.as_str(), optimizer_settings, vec![]);
+        let state = project
+            .write()
+            .expect("Sync")
+            .contract_states
+            .remove(path.as_str());
+        match state {
+            Some(ContractState::Build(build)) => Ok((path, build.build)),
+            Some(ContractState::Error(error)) => Err(error),
+            _ => anyhow::bail!("Contract `{}` has not been built", path),
+        }
+    }
+
+    ///
+    /// Whether the bytecode contains the byte sequence.
+    ///
+    fn contains(bytecode: &[u8], bytes: &[u8]) -> bool {
+        !bytes.is_empty() && bytecode.windows(bytes.len()).any(|window| window == bytes)
+    }
+}
+
+impl std::fmt::Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_verified() {
+            return write!(
+                f,
+                "Contract `{}` bytecode is verified, its hash is 0x{}",
+                self.contract, self.actual_hash
+            );
+        }
+
+        writeln!(
+            f,
+            "Contract `{}` bytecode does not match the build info:",
+            self.contract
+        )?;
+        writeln!(
+            f,
+            "    length: {} bytes ({} words) supplied, {} bytes ({} words) recompiled",
+            self.expected.len(),
+            self.expected.len() / compiler_common::SIZE_FIELD,
+            self.actual.len(),
+            self.actual.len() / compiler_common::SIZE_FIELD,
+        )?;
+        let metadata_suffix = match (
+            Self::metadata_suffix(self.expected.as_slice()),
+            Self::metadata_suffix(self.actual.as_slice()),
+        ) {
+            (Some(expected), Some(actual)) if expected == actual => "the same".to_owned(),
+            (Some(expected), Some(actual)) => format!(
+                "0x{} supplied, 0x{} recompiled",
+                hex::encode(expected),
+                hex::encode(actual)
+            ),
+            (Some(_), None) => "only in the supplied bytecode".to_owned(),
+            (None, Some(_)) => "only in the recompiled bytecode".to_owned(),
+            (None, None) => "none".to_owned(),
+        };
+        writeln!(f, "    metadata hash suffix: {}", metadata_suffix)?;
+        if let Some(index) = self.first_different_word() {
+            let word = |bytecode: &[u8]| {
+                bytecode
+                    .chunks(compiler_common::SIZE_FIELD)
+                    .nth(index)
+                    .map(|word| format!("0x{}", hex::encode(word)))
+                    .unwrap_or_else(|| "none".to_owned())
+            };
+            writeln!(
+                f,
+                "    first differing word: {}, {} supplied, {} recompiled",
+                index,
+                word(self.expected.as_slice()),
+                word(self.actual.as_slice())
+            )?;
+        }
+        if self.causes.is_empty() {
+            write!(
+                f,
+                "    likely cause: unknown, the source code or the settings may be different"
+            )?;
+        } else {
+            for (index, cause) in self.causes.iter().enumerate() {
+                if index > 0 {
+                    writeln!(f)?;
+                }
+                write!(f, "    likely cause: {}", cause)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Verification;
+
+    fn verification(expected: Vec<u8>, actual: Vec<u8>) -> Verification {
+        Verification {
+            contract: "Test.sol:Test".to_owned(),
+            expected,
+            actual,
+            actual_hash: String::new(),
+            causes: vec![],
+        }
+    }
+
+    #[test]
+    fn first_different_word() {
+        let mut actual = vec![0; 3 * compiler_common::SIZE_FIELD];
+        actual[2 * compiler_common::SIZE_FIELD] = 1;
+
+        assert_eq!(
+            verification(actual.clone(), actual.clone()).first_different_word(),
+            None
+        );
+        assert_eq!(
+            verification(vec![0; 3 * compiler_common::SIZE_FIELD], actual.clone())
+                .first_different_word(),
+            Some(2)
+        );
+        assert_eq!(
+            verification(actual[..compiler_common::SIZE_FIELD].to_vec(), actual)
+                .first_different_word(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn metadata_suffix() {
+        let mut bytecode = vec![1; compiler_common::SIZE_FIELD];
+        bytecode.extend(vec![0; compiler_common::SIZE_FIELD]);
+        bytecode.extend(vec![2; compiler_common::SIZE_FIELD]);
+
+        assert_eq!(
+            Verification::metadata_suffix(bytecode.as_slice()),
+            Some(vec![2; compiler_common::SIZE_FIELD].as_slice())
+        );
+        assert_eq!(
+            Verification::metadata_suffix(&bytecode[compiler_common::SIZE_FIELD..]),
+            None
+        );
+    }
+}
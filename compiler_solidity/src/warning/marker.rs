@@ -0,0 +1,258 @@
+//!
+//! The warning suppression source code markers.
+//!
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::warning::Warning;
+
+///
+/// The warning suppression markers of a source code file.
+///
+/// The markers are Solidity comments:
+/// - `// zksolc-disable-next-line [codes]` suppresses the warnings on the next line;
+/// - `// zksolc-disable [codes]` suppresses the warnings until the `// zksolc-enable [codes]`
+///   marker or the end of the file.
+///
+/// If no codes are specified, all warnings are suppressed or enabled. The unknown codes are
+/// ignored and reported as warnings.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Markers {
+    /// The suppressed warnings with their byte offset ranges.
+    ranges: Vec<(Warning, std::ops::Range<usize>)>,
+    /// The unknown warning codes with the byte offset ranges of their marker comments.
+    pub unknown_codes: Vec<(String, std::ops::Range<usize>)>,
+}
+
+impl Markers {
+    /// The next line suppression marker.
+    pub const DISABLE_NEXT_LINE: &'static str = "zksolc-disable-next-line";
+
+    /// The range start suppression marker.
+    pub const DISABLE: &'static str = "zksolc-disable";
+
+    /// The range end suppression marker.
+    pub const ENABLE: &'static str = "zksolc-enable";
+
+    ///
+    /// Parses the markers from the source code comments.
+    ///
+    pub fn from_source_code(source_code: &str) -> Self {
+        let mut ranges = Vec::new();
+        let mut unknown_codes = Vec::new();
+        let mut open = BTreeMap::new();
+
+        for (comment_range, comment) in Self::comments(source_code).into_iter() {
+            let (marker, codes) = match Self::parse_comment(comment) {
+                Some(comment) => comment,
+                None => continue,
+            };
+            let warnings = if codes.is_empty() {
+                Warning::ALL.to_vec()
+            } else {
+                codes
+                    .into_iter()
+                    .filter_map(|code| match Warning::from_str(code) {
+                        Ok(warning) => Some(warning),
+                        Err(_error) => {
+                            unknown_codes.push((code.to_owned(), comment_range.clone()));
+                            None
+                        }
+                    })
+                    .collect()
+            };
+
+            let line_start = source_code[..comment_range.start]
+                .rfind('\n')
+                .map(|index| index + 1)
+                .unwrap_or_default();
+            match marker {
+                Self::DISABLE_NEXT_LINE => {
+                    let next_line_start = Self::line_end(source_code, comment_range.end);
+                    let next_line_end = Self::line_end(source_code, next_line_start);
+                    for warning in warnings.into_iter() {
+                        ranges.push((warning, next_line_start..next_line_end));
+                    }
+                }
+                Self::DISABLE => {
+                    for warning in warnings.into_iter() {
+                        open.entry(warning).or_insert(line_start);
+                    }
+                }
+                Self::ENABLE => {
+                    for warning in warnings.into_iter() {
+                        if let Some(start) = open.remove(&warning) {
+                            ranges.push((warning, start..line_start));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        for (warning, start) in open.into_iter() {
+            ranges.push((warning, start..source_code.len()));
+        }
+
+        Self {
+            ranges,
+            unknown_codes,
+        }
+    }
+
+    ///
+    /// Checks whether the warning at the byte `offset` is suppressed.
+    ///
+    pub fn is_suppressed(&self, warning: Warning, offset: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|(suppressed, range)| *suppressed == warning && range.contains(&offset))
+    }
+
+    ///
+    /// Returns the comments of the source code with their byte offset ranges.
+    ///
+    /// The comment delimiters inside the string literals are skipped.
+    ///
+    fn comments(source_code: &str) -> Vec<(std::ops::Range<usize>, &str)> {
+        let bytes = source_code.as_bytes();
+        let mut comments = Vec::new();
+        let mut quote = None;
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index];
+            match quote {
+                Some(_) if byte == b'\\' => index += 1,
+                Some(opening) if byte == opening || byte == b'\n' => quote = None,
+                Some(_) => {}
+                None => match (byte, bytes.get(index + 1)) {
+                    (b'"', _) | (b'\'', _) => quote = Some(byte),
+                    (b'/', Some(b'/')) => {
+                        let end = source_code[index..]
+                            .find('\n')
+                            .map(|length| index + length)
+                            .unwrap_or(source_code.len());
+                        comments.push((index..end, &source_code[index + 2..end]));
+                        index = end;
+                        continue;
+                    }
+                    (b'/', Some(b'*')) => {
+                        let (text_end, end) = match source_code[index + 2..].find("*/") {
+                            Some(length) => (index + 2 + length, index + 4 + length),
+                            None => (source_code.len(), source_code.len()),
+                        };
+                        comments.push((index..end, &source_code[index + 2..text_end]));
+                        index = end;
+                        continue;
+                    }
+                    _ => {}
+                },
+            }
+            index += 1;
+        }
+        comments
+    }
+
+    ///
+    /// Returns the byte offset after the end of the line containing `offset`.
+    ///
+    fn line_end(source_code: &str, offset: usize) -> usize {
+        source_code[offset..]
+            .find('\n')
+            .map(|length| offset + length + 1)
+            .unwrap_or(source_code.len())
+    }
+
+    ///
+    /// Returns the marker and its warning codes, if the comment is a marker.
+    ///
+    fn parse_comment(comment: &str) -> Option<(&'static str, Vec<&str>)> {
+        let comment = comment.trim();
+        let marker = [Self::DISABLE_NEXT_LINE, Self::DISABLE, Self::ENABLE]
+            .into_iter()
+            .find(|marker| {
+                matches!(
+                    comment.strip_prefix(marker),
+                    Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace)
+                )
+            })?;
+        let codes = comment[marker.len()..]
+            .split(|character: char| character == ',' || character.is_whitespace())
+            .filter(|code| !code.is_empty())
+            .collect();
+
+        Some((marker, codes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::warning::marker::Markers;
+    use crate::warning::Warning;
+
+    #[test]
+    fn disable_next_line() {
+        let source_code = r#"contract Test {
+    function f(bytes32 h, uint8 v, bytes32 r, bytes32 s) public pure returns (address) {
+        // zksolc-disable-next-line ZK1001
+        return ecrecover(h, v, r, s);
+    }
+}
+"#;
+        let offset = source_code.find("ecrecover").expect("Always exists");
+
+        let markers = Markers::from_source_code(source_code);
+        assert!(markers.is_suppressed(Warning::EcRecover, offset));
+        assert!(!markers.is_suppressed(Warning::ExtCodeSize, offset));
+        assert!(!markers.is_suppressed(Warning::EcRecover, 0));
+    }
+
+    #[test]
+    fn disable_enable() {
+        let source_code = r#"contract Test {
+    /* zksolc-disable */
+    function f() public view returns (uint256 size) {
+        assembly { size := extcodesize(caller()) }
+    }
+    // zksolc-enable extcodesize
+    function g() public view returns (uint256 size) {
+        assembly { size := extcodesize(caller()) }
+    }
+}
+"#;
+        let first = source_code.find("extcodesize").expect("Always exists");
+        let second = source_code.rfind("extcodesize").expect("Always exists");
+
+        let markers = Markers::from_source_code(source_code);
+        assert!(markers.is_suppressed(Warning::ExtCodeSize, first));
+        assert!(!markers.is_suppressed(Warning::ExtCodeSize, second));
+        assert!(markers.is_suppressed(Warning::EcRecover, second));
+    }
+
+    #[test]
+    fn unknown_code() {
+        let source_code =
+            "// zksolc-disable-next-line ZK9999 ecrecover\nreturn ecrecover(h, v, r, s);\n";
+        let offset = source_code.find("return").expect("Always exists");
+
+        let markers = Markers::from_source_code(source_code);
+        assert_eq!(markers.unknown_codes, vec![("ZK9999".to_owned(), 0..44)]);
+        assert!(markers.is_suppressed(Warning::EcRecover, offset));
+    }
+
+    #[test]
+    fn string_literal() {
+        let source_code = r#"contract Test {
+    string constant s = "// zksolc-disable";
+    function f() public view returns (uint256 size) {
+        assembly { size := extcodesize(caller()) }
+    }
+}
+"#;
+        let offset = source_code.find("extcodesize").expect("Always exists");
+
+        let markers = Markers::from_source_code(source_code);
+        assert!(!markers.is_suppressed(Warning::ExtCodeSize, offset));
+    }
+}
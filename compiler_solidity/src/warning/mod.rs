@@ -0,0 +1,187 @@
+//!
+//! The zkSync compatibility warning.
+//!
+
+pub mod marker;
+pub mod settings;
+
+use std::str::FromStr;
+
+///
+/// The zkSync compatibility warning registry.
+///
+/// The codes are stable, so they can be used to suppress the warnings.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Warning {
+    /// The `ecrecover` function is used for the signature validation.
+    EcRecover,
+    /// The `extcodesize` instruction is used.
+    ExtCodeSize,
+    /// The `transfer` or `send` address method relies on the gas stipend.
+    TransferSend,
+    /// The `tx.origin` value is used.
+    TxOrigin,
+    /// The `block.coinbase` or `block.difficulty` value is used.
+    CoinbaseDifficulty,
+    /// The `gasleft` function is used.
+    GasLeft,
+    /// The `selfdestruct` function or instruction is used.
+    SelfDestruct,
+    /// The `pc`, `extcodecopy`, or `callcode` instruction is used in inline assembly.
+    UnsupportedAssembly,
+    /// The `codesize` instruction is used in inline assembly.
+    CodeSize,
+    /// The Ethereal IR block is truncated as unreachable because of an invalid stack state.
+    EthIRTruncation,
+}
+
+impl Warning {
+    /// The list of all registered warnings.
+    pub const ALL: [Self; 10] = [
+        Self::EcRecover,
+        Self::ExtCodeSize,
+        Self::TransferSend,
+        Self::TxOrigin,
+        Self::CoinbaseDifficulty,
+        Self::GasLeft,
+        Self::SelfDestruct,
+        Self::UnsupportedAssembly,
+        Self::CodeSize,
+        Self::EthIRTruncation,
+    ];
+
+    ///
+    /// Returns the stable warning code.
+    ///
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::EcRecover => "ZK1001",
+            Self::ExtCodeSize => "ZK1002",
+            Self::TransferSend => "ZK1003",
+            Self::TxOrigin => "ZK1004",
+            Self::CoinbaseDifficulty => "ZK1005",
+            Self::GasLeft => "ZK1006",
+            Self::SelfDestruct => "ZK1007",
+            Self::UnsupportedAssembly => "ZK1008",
+            Self::CodeSize => "ZK1009",
+            Self::EthIRTruncation => "ZK1010",
+        }
+    }
+
+    ///
+    /// Returns the warning name, which can be used instead of the code.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::EcRecover => "ecrecover",
+            Self::ExtCodeSize => "extcodesize",
+            Self::TransferSend => "transfer-send",
+            Self::TxOrigin => "tx-origin",
+            Self::CoinbaseDifficulty => "coinbase-difficulty",
+            Self::GasLeft => "gasleft",
+            Self::SelfDestruct => "selfdestruct",
+            Self::UnsupportedAssembly => "unsupported-assembly",
+            Self::CodeSize => "codesize",
+            Self::EthIRTruncation => "ethir-truncation",
+        }
+    }
+
+    ///
+    /// Returns the warning message.
+    ///
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::EcRecover => {
+                r#"
+┌─────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: It seems like you are using ecrecover to validate signature of a user account. │
+│ zkSync 2.0 will come with native account abstraction support. It is highly recommended  │
+│ NOT to rely on the fact that the account has ECDSA private key attached to it, since    │
+│ they may be ruled by a multisig and use other signature scheme. You can read more about │
+│ how you can get ready for the future AA launch here:                                    │
+│ https://v2-docs.zksync.io/dev/zksync-v2/aa.html#important-account-abstraction-support   │
+└─────────────────────────────────────────────────────────────────────────────────────────┘"#
+            }
+            Self::ExtCodeSize => {
+                r#"
+┌─────────────────────────────────────────────────────────────────────────────────────────┐
+│ Warning: It seems like your code or one of its dependencies uses extcodesize. This      │
+│ opcode is most often used for the following:                                            │
+│     - to detect whether an address belongs to smart contracts                           │
+│     - to detect whether the deploy code execution has ended                             │
+│ zkSync 2.0 will support account abstraction by default (so every account will be a      │
+│ smart contract, even private-key controlled EOAs). So none of the use-cases above will  │
+│ work. Please do your best to avoid differentiating between contracts and non-contracts. │
+└─────────────────────────────────────────────────────────────────────────────────────────┘"#
+            }
+            Self::TransferSend => {
+                "`transfer` and `send` forward the fixed 2300 gas stipend, which is not \
+                 enough for most zkSync accounts, since every account is a smart contract there. \
+                 Use `call` with an explicit value and check the result instead."
+            }
+            Self::TxOrigin => {
+                "`tx.origin` must not be used for the authorization. With the native \
+                 account abstraction, the transaction origin is an account contract, which may \
+                 be controlled by any signature scheme or by several owners."
+            }
+            Self::CoinbaseDifficulty => {
+                "`block.coinbase` and `block.difficulty` have no EVM meaning on zkSync. \
+                 The coinbase is the bootloader address, and the difficulty is a constant, so \
+                 neither can be used as a source of randomness or miner data."
+            }
+            Self::GasLeft => {
+                "`gasleft()` returns the zkEVM gas, whose costs differ from the EVM ones. \
+                 The logic depending on the exact amount of the remaining gas may behave \
+                 differently."
+            }
+            Self::SelfDestruct => {
+                "`selfdestruct` is not supported by zkSync. The contract code and storage \
+                 will not be removed, and the balance will not be transferred."
+            }
+            Self::UnsupportedAssembly => {
+                "The `pc`, `extcodecopy`, and `callcode` instructions are not supported \
+                 by zkSync, since the zkEVM bytecode is different from the EVM one. The contract \
+                 will fail to compile or behave differently."
+            }
+            Self::CodeSize => {
+                "`codesize()` must not be used to detect whether the constructor is being \
+                 executed. On zkSync, the deploy and runtime code are the same bytecode, so the \
+                 code size differs from the EVM one and cannot be used as a constructor check."
+            }
+            Self::EthIRTruncation => {
+                "An Ethereal IR block is reached with a stack state it cannot be translated \
+                 with, so the rest of the block is considered unreachable and replaced with \
+                 `INVALID`. If it is reachable in fact, the contract will behave differently."
+            }
+        }
+    }
+}
+
+impl FromStr for Warning {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|warning| warning.code().eq_ignore_ascii_case(value) || warning.name() == value)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown warning `{}`. Available warnings: {}",
+                    value,
+                    Self::ALL
+                        .iter()
+                        .map(|warning| format!("{} ({})", warning.code(), warning.name()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! The zkSync compatibility warning settings.
+//!
+
+use crate::warning::Warning;
+
+///
+/// The zkSync compatibility warning settings.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// The warnings suppressed in all source code files.
+    pub suppressed: Vec<Warning>,
+    /// Whether the warnings must be reported as errors.
+    pub as_errors: bool,
+}
+
+impl Settings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(suppressed: Vec<Warning>, as_errors: bool) -> Self {
+        Self {
+            suppressed,
+            as_errors,
+        }
+    }
+
+    ///
+    /// Checks whether the warning is suppressed globally.
+    ///
+    pub fn is_suppressed(&self, warning: Warning) -> bool {
+        self.suppressed.contains(&warning)
+    }
+}
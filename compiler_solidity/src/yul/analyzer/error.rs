@@ -0,0 +1,116 @@
+//!
+//! The Yul IR semantic analyzer error.
+//!
+
+use crate::yul::lexer::token::location::Location;
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    #[error("{location} Undeclared variable `{identifier}`")]
+    UndeclaredVariable {
+        /// The variable usage location.
+        location: Location,
+        /// The variable identifier.
+        identifier: String,
+    },
+    #[error("{location} Undeclared function `{identifier}`")]
+    UndeclaredFunction {
+        /// The function call location.
+        location: Location,
+        /// The function identifier.
+        identifier: String,
+    },
+    #[error("{location} Function `{identifier}` expects {expected} arguments, found {found}")]
+    InvalidNumberOfArguments {
+        /// The function call location.
+        location: Location,
+        /// The function identifier.
+        identifier: String,
+        /// The expected number of arguments.
+        expected: usize,
+        /// The actual number of arguments.
+        found: usize,
+    },
+    #[error("{location} The `{statement}` statement is only allowed inside a for-loop body")]
+    OutsideOfLoop {
+        /// The statement location.
+        location: Location,
+        /// The statement keyword.
+        statement: &'static str,
+    },
+    #[error("{location} The `leave` statement is only allowed inside a function")]
+    OutsideOfFunction {
+        /// The statement location.
+        location: Location,
+    },
+    #[error("{location} The identifier `{identifier}` is already declared at {previous}")]
+    DuplicateDeclaration {
+        /// The repeated declaration location.
+        location: Location,
+        /// The declared identifier.
+        identifier: String,
+        /// The original declaration location.
+        previous: Location,
+    },
+    #[error("{location} The switch case value `{value}` is already matched at {previous}")]
+    DuplicateCase {
+        /// The repeated case location.
+        location: Location,
+        /// The case literal.
+        value: String,
+        /// The original case location.
+        previous: Location,
+    },
+    #[error("{location} The literal `{value}` does not fit into {bitlength} bits")]
+    LiteralTooWide {
+        /// The literal location.
+        location: Location,
+        /// The literal value.
+        value: String,
+        /// The maximal allowed bitlength.
+        bitlength: usize,
+    },
+    #[error("{location} The literal `{value}` is not a valid integer")]
+    InvalidLiteral {
+        /// The literal location.
+        location: Location,
+        /// The literal value.
+        value: String,
+    },
+}
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UndeclaredVariable { location, .. } => *location,
+            Self::UndeclaredFunction { location, .. } => *location,
+            Self::InvalidNumberOfArguments { location, .. } => *location,
+            Self::OutsideOfLoop { location, .. } => *location,
+            Self::OutsideOfFunction { location } => *location,
+            Self::DuplicateDeclaration { location, .. } => *location,
+            Self::DuplicateCase { location, .. } => *location,
+            Self::LiteralTooWide { location, .. } => *location,
+            Self::InvalidLiteral { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the length of the erroneous source code fragment.
+    ///
+    pub fn length(&self) -> usize {
+        match self {
+            Self::UndeclaredVariable { identifier, .. } => identifier.len(),
+            Self::UndeclaredFunction { identifier, .. } => identifier.len(),
+            Self::InvalidNumberOfArguments { identifier, .. } => identifier.len(),
+            Self::OutsideOfLoop { statement, .. } => statement.len(),
+            Self::OutsideOfFunction { .. } => "leave".len(),
+            Self::DuplicateDeclaration { identifier, .. } => identifier.len(),
+            Self::DuplicateCase { value, .. } => value.len(),
+            Self::LiteralTooWide { value, .. } => value.len(),
+            Self::InvalidLiteral { value, .. } => value.len(),
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//!
+//! The Yul IR semantic analyzer.
+//!
+
+pub mod error;
+pub mod scope;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+
+use num::Num;
+
+use crate::yul::error::Error;
+use crate::yul::lexer::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::Statement;
+
+use self::error::Error as AnalyzerError;
+use self::scope::Function;
+use self::scope::Scope;
+
+///
+/// The Yul IR semantic analyzer.
+///
+/// Resolves the identifiers and checks the statement placement rules, which would otherwise
+/// only be caught during the LLVM IR generation. All the errors are collected in a single run.
+///
+#[derive(Debug, Default)]
+pub struct Analyzer {
+    /// The scope stack, where the innermost scope is the last one.
+    scopes: Vec<Scope>,
+    /// The number of `for` loop bodies enclosing the current statement within its function.
+    loop_depth: usize,
+    /// The number of function definitions enclosing the current statement.
+    function_depth: usize,
+    /// The errors collected so far.
+    errors: Vec<Error>,
+}
+
+impl Analyzer {
+    ///
+    /// Analyzes the object and its inner objects.
+    ///
+    /// Returns all the errors found, in the order of their appearance.
+    ///
+    pub fn analyze(object: &Object) -> Result<(), Vec<Error>> {
+        let mut analyzer = Self::default();
+        analyzer.object(object);
+        if analyzer.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(analyzer.errors)
+        }
+    }
+
+    ///
+    /// Analyzes the object with a clean state, as objects do not share any identifiers.
+    ///
+    fn object(&mut self, object: &Object) {
+        let mut analyzer = Self::default();
+        analyzer.block(&object.code.block, Scope::default());
+        self.errors.append(&mut analyzer.errors);
+
+        if let Some(ref inner_object) = object.inner_object {
+            self.object(inner_object);
+        }
+    }
+
+    ///
+    /// Analyzes the block, opening the `scope` for its statements.
+    ///
+    fn block(&mut self, block: &Block, scope: Scope) {
+        self.scopes.push(scope);
+        self.statements(block.statements.as_slice());
+        self.scopes.pop();
+    }
+
+    ///
+    /// Analyzes the statements in the innermost scope.
+    ///
+    /// The function signatures are hoisted, so they are declared before the statements are checked.
+    ///
+    fn statements(&mut self, statements: &[Statement]) {
+        for statement in statements.iter() {
+            if let Statement::FunctionDefinition(function) = statement {
+                self.declare_function(function);
+            }
+        }
+
+        for statement in statements.iter() {
+            self.statement(statement);
+        }
+    }
+
+    ///
+    /// Analyzes the statement.
+    ///
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Object(object) => self.object(object),
+            Statement::Code(code) => {
+                let mut analyzer = Self::default();
+                analyzer.block(&code.block, Scope::default());
+                self.errors.append(&mut analyzer.errors);
+            }
+            Statement::Block(block) => self.block(block, Scope::default()),
+            Statement::Expression(expression) => self.expression(expression),
+            Statement::FunctionDefinition(function) => self.function_definition(function),
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(ref expression) = declaration.expression {
+                    self.expression(expression);
+                }
+                for binding in declaration.bindings.iter() {
+                    self.declare_variable(binding);
+                }
+            }
+            Statement::Assignment(assignment) => {
+                for binding in assignment.bindings.iter() {
+                    self.resolve_variable(binding.location, binding.inner.as_str());
+                }
+                self.expression(&assignment.initializer);
+            }
+            Statement::IfConditional(conditional) => {
+                self.expression(&conditional.condition);
+                self.block(&conditional.block, Scope::default());
+            }
+            Statement::Switch(switch) => self.switch(switch),
+            Statement::ForLoop(r#loop) => self.for_loop(r#loop),
+            Statement::Continue(location) => self.check_loop(*location, "continue"),
+            Statement::Break(location) => self.check_loop(*location, "break"),
+            Statement::Leave(location) => {
+                if self.function_depth == 0 {
+                    self.errors.push(
+                        AnalyzerError::OutsideOfFunction {
+                            location: *location,
+                        }
+                        .into(),
+                    );
+                }
+            }
+        }
+    }
+
+    ///
+    /// Analyzes the function definition body.
+    ///
+    /// The function body cannot access the variables of the enclosing scopes, and the loops
+    /// around the definition do not apply to its body.
+    ///
+    fn function_definition(&mut self, function: &FunctionDefinition) {
+        let loop_depth = self.loop_depth;
+        self.loop_depth = 0;
+        self.function_depth += 1;
+
+        self.scopes.push(Scope::new_function());
+        for identifier in function.arguments.iter().chain(function.result.iter()) {
+            self.declare_variable(identifier);
+        }
+        self.block(&function.body, Scope::default());
+        self.scopes.pop();
+
+        self.function_depth -= 1;
+        self.loop_depth = loop_depth;
+    }
+
+    ///
+    /// Analyzes the switch statement.
+    ///
+    fn switch(&mut self, switch: &Switch) {
+        self.expression(&switch.expression);
+
+        let mut values: HashMap<String, Location> = HashMap::with_capacity(switch.cases.len());
+        for case in switch.cases.iter() {
+            self.literal(&case.literal);
+            if let Some(value) = Self::literal_value(&case.literal) {
+                if let Some(previous) = values.get(value.as_str()) {
+                    self.errors.push(
+                        AnalyzerError::DuplicateCase {
+                            location: case.location,
+                            value: case.literal.inner.to_string(),
+                            previous: *previous,
+                        }
+                        .into(),
+                    );
+                } else {
+                    values.insert(value, case.location);
+                }
+            }
+            self.block(&case.block, Scope::default());
+        }
+
+        if let Some(ref default) = switch.default {
+            self.block(default, Scope::default());
+        }
+    }
+
+    ///
+    /// Analyzes the for-loop statement.
+    ///
+    /// The variables declared in the initializer are visible in the rest of the loop.
+    ///
+    fn for_loop(&mut self, r#loop: &ForLoop) {
+        self.scopes.push(Scope::default());
+        self.statements(r#loop.initializer.statements.as_slice());
+        self.expression(&r#loop.condition);
+        self.block(&r#loop.finalizer, Scope::default());
+
+        self.loop_depth += 1;
+        self.block(&r#loop.body, Scope::default());
+        self.loop_depth -= 1;
+
+        self.scopes.pop();
+    }
+
+    ///
+    /// Analyzes the expression.
+    ///
+    fn expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::FunctionCall(call) => {
+                if let FunctionName::UserDefined(ref name) = call.name {
+                    match self.get_function(name.as_str()) {
+                        Some(function) if function.input_size != call.arguments.len() => {
+                            self.errors.push(
+                                AnalyzerError::InvalidNumberOfArguments {
+                                    location: call.location,
+                                    identifier: name.to_owned(),
+                                    expected: function.input_size,
+                                    found: call.arguments.len(),
+                                }
+                                .into(),
+                            );
+                        }
+                        Some(_) => {}
+                        None => {
+                            self.errors.push(
+                                AnalyzerError::UndeclaredFunction {
+                                    location: call.location,
+                                    identifier: name.to_owned(),
+                                }
+                                .into(),
+                            );
+                        }
+                    }
+                }
+
+                for argument in call.arguments.iter() {
+                    self.expression(argument);
+                }
+            }
+            Expression::Identifier(identifier) => {
+                self.resolve_variable(identifier.location, identifier.inner.as_str())
+            }
+            Expression::Literal(literal) => self.literal(literal),
+        }
+    }
+
+    ///
+    /// Checks that the integer literal is valid and fits into the field.
+    ///
+    fn literal(&mut self, literal: &Literal) {
+        if let LexicalLiteral::Integer(ref integer) = literal.inner {
+            let value = match Self::integer_value(integer) {
+                Some(value) => value,
+                None => {
+                    self.errors.push(
+                        AnalyzerError::InvalidLiteral {
+                            location: literal.location,
+                            value: integer.to_string(),
+                        }
+                        .into(),
+                    );
+                    return;
+                }
+            };
+            if value.bits() > compiler_common::BITLENGTH_FIELD as u64 {
+                self.errors.push(
+                    AnalyzerError::LiteralTooWide {
+                        location: literal.location,
+                        value: integer.to_string(),
+                        bitlength: compiler_common::BITLENGTH_FIELD,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    ///
+    /// Declares the function in the innermost scope.
+    ///
+    fn declare_function(&mut self, function: &FunctionDefinition) {
+        if let Some(previous) = self.get_function(function.identifier.as_str()) {
+            self.errors.push(
+                AnalyzerError::DuplicateDeclaration {
+                    location: function.location,
+                    identifier: function.identifier.to_owned(),
+                    previous: previous.location,
+                }
+                .into(),
+            );
+            return;
+        }
+
+        let mut input_size = function.arguments.len();
+        if function
+            .identifier
+            .contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX)
+        {
+            // the ABI argument is removed from the definition by the parser
+            input_size += 1;
+        }
+
+        self.scopes
+            .last_mut()
+            .expect("Always exists")
+            .functions
+            .insert(
+                function.identifier.to_owned(),
+                Function::new(function.location, input_size),
+            );
+    }
+
+    ///
+    /// Declares the variable in the innermost scope.
+    ///
+    fn declare_variable(&mut self, identifier: &Identifier) {
+        if let Some(previous) = self.get_variable(identifier.inner.as_str()) {
+            self.errors.push(
+                AnalyzerError::DuplicateDeclaration {
+                    location: identifier.location,
+                    identifier: identifier.inner.to_owned(),
+                    previous,
+                }
+                .into(),
+            );
+            return;
+        }
+
+        self.scopes
+            .last_mut()
+            .expect("Always exists")
+            .variables
+            .insert(identifier.inner.to_owned(), identifier.location);
+    }
+
+    ///
+    /// Checks that the variable is visible from the current scope.
+    ///
+    fn resolve_variable(&mut self, location: Location, identifier: &str) {
+        if self.get_variable(identifier).is_none() {
+            self.errors.push(
+                AnalyzerError::UndeclaredVariable {
+                    location,
+                    identifier: identifier.to_owned(),
+                }
+                .into(),
+            );
+        }
+    }
+
+    ///
+    /// Checks that the `break` or `continue` statement is inside a loop body.
+    ///
+    fn check_loop(&mut self, location: Location, statement: &'static str) {
+        if self.loop_depth == 0 {
+            self.errors.push(
+                AnalyzerError::OutsideOfLoop {
+                    location,
+                    statement,
+                }
+                .into(),
+            );
+        }
+    }
+
+    ///
+    /// Returns the variable declaration location, if the variable is visible.
+    ///
+    /// The lookup stops at the innermost function boundary.
+    ///
+    fn get_variable(&self, identifier: &str) -> Option<Location> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(location) = scope.variables.get(identifier) {
+                return Some(*location);
+            }
+            if scope.is_function {
+                break;
+            }
+        }
+        None
+    }
+
+    ///
+    /// Returns the function signature, if the function is visible.
+    ///
+    fn get_function(&self, identifier: &str) -> Option<Function> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.functions.get(identifier).copied())
+    }
+
+    ///
+    /// Returns the normalized literal value used to compare switch cases.
+    ///
+    fn literal_value(literal: &Literal) -> Option<String> {
+        match literal.inner {
+            LexicalLiteral::Boolean(BooleanLiteral::False) => Some("0".to_owned()),
+            LexicalLiteral::Boolean(BooleanLiteral::True) => Some("1".to_owned()),
+            LexicalLiteral::Integer(ref integer) => {
+                Self::integer_value(integer).map(|value| value.to_string())
+            }
+            LexicalLiteral::String(ref string) if !string.is_hexadecimal => {
+                Some(format!("\"{}\"", string.inner))
+            }
+            LexicalLiteral::String(_) => None,
+        }
+    }
+
+    ///
+    /// Parses the integer literal value.
+    ///
+    /// Returns `None` if the literal has no digits, e.g. a bare `0x` accepted by the lexer.
+    ///
+    fn integer_value(integer: &IntegerLiteral) -> Option<num::BigUint> {
+        match integer {
+            IntegerLiteral::Decimal { inner } => {
+                num::BigUint::from_str_radix(inner.as_str(), compiler_common::BASE_DECIMAL)
+            }
+            IntegerLiteral::Hexadecimal { inner } => num::BigUint::from_str_radix(
+                &inner["0x".len()..],
+                compiler_common::BASE_HEXADECIMAL,
+            ),
+        }
+        .ok()
+    }
+}
@@ -0,0 +1,58 @@
+//!
+//! The Yul IR semantic analyzer scope.
+//!
+
+use std::collections::HashMap;
+
+use crate::yul::lexer::token::location::Location;
+
+///
+/// The Yul IR semantic analyzer scope.
+///
+/// A scope is opened by every block, and additionally by every function definition, where the
+/// latter holds the function arguments and return variables.
+///
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// The variables declared in the scope, with their declaration locations.
+    pub variables: HashMap<String, Location>,
+    /// The functions declared in the scope.
+    pub functions: HashMap<String, Function>,
+    /// Whether the scope is a function boundary, so the outer variables are not visible.
+    pub is_function: bool,
+}
+
+///
+/// The function signature, which is hoisted to the beginning of its block.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Function {
+    /// The declaration location.
+    pub location: Location,
+    /// The number of arguments expected at the call site.
+    pub input_size: usize,
+}
+
+impl Scope {
+    ///
+    /// Creates a function boundary scope.
+    ///
+    pub fn new_function() -> Self {
+        Self {
+            is_function: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, input_size: usize) -> Self {
+        Self {
+            location,
+            input_size,
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//!
+//! The Yul IR semantic analyzer tests.
+//!
+
+use crate::yul::analyzer::error::Error;
+use crate::yul::analyzer::Analyzer;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::statement::object::Object;
+
+fn analyze(input: &str) -> Result<(), Vec<crate::yul::error::Error>> {
+    let mut lexer = Lexer::new(input.to_owned());
+    let object = Object::parse(&mut lexer, None).expect("Invalid test data");
+    Analyzer::analyze(&object)
+}
+
+#[test]
+fn ok() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := 42
+                for { let i := 0 } lt(i, x) { i := add(i, 1) } {
+                    if eq(i, 5) { continue }
+                    if eq(i, 7) { break }
+                    x := test(i, x)
+                }
+                switch x
+                case 0 { revert(0, 0) }
+                case 0x01 { revert(0, 0) }
+                default { mstore(0, x) }
+                return(0, 32)
+            }
+            function test(a, b) -> result {
+                if eq(a, b) { leave }
+                result := add(a, b)
+            }
+        }
+    }
+}
+    "#;
+
+    assert_eq!(analyze(input), Ok(()));
+}
+
+#[test]
+fn error_undeclared_variable() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let x := 42
+                y := x
+                return(x, z)
+            }
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::UndeclaredVariable {
+                location: Location::new(12, 17),
+                identifier: "y".to_owned(),
+            }
+            .into(),
+            Error::UndeclaredVariable {
+                location: Location::new(13, 27),
+                identifier: "z".to_owned(),
+            }
+            .into(),
+        ])
+    );
+}
+
+#[test]
+fn error_undeclared_variable_outer_function_scope() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := 42
+            function test() -> result {
+                result := x
+            }
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::UndeclaredVariable {
+            location: Location::new(7, 27),
+            identifier: "x".to_owned(),
+        }
+        .into()])
+    );
+}
+
+#[test]
+fn error_undeclared_function() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := test(42)
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::UndeclaredFunction {
+            location: Location::new(5, 22),
+            identifier: "test".to_owned(),
+        }
+        .into()])
+    );
+}
+
+#[test]
+fn error_invalid_number_of_arguments() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := test(42)
+            return(0, 0)
+        }
+        function test(a, b) -> result {
+            result := add(a, b)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::InvalidNumberOfArguments {
+            location: Location::new(5, 22),
+            identifier: "test".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into()])
+    );
+}
+
+#[test]
+fn error_outside_of_loop() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            for { let i := 0 } lt(i, 10) { i := add(i, 1) } {
+                function test() {
+                    break
+                }
+            }
+            continue
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::OutsideOfLoop {
+                location: Location::new(7, 21),
+                statement: "break",
+            }
+            .into(),
+            Error::OutsideOfLoop {
+                location: Location::new(10, 13),
+                statement: "continue",
+            }
+            .into(),
+        ])
+    );
+}
+
+#[test]
+fn error_outside_of_function() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            if callvalue() { leave }
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::OutsideOfFunction {
+            location: Location::new(5, 30),
+        }
+        .into()])
+    );
+}
+
+#[test]
+fn error_duplicate_declaration() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := 1
+            let y, x := test()
+            {
+                let y := 3
+            }
+        }
+        function test() -> a, b {}
+        function test() -> a, b {}
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::DuplicateDeclaration {
+                location: Location::new(12, 18),
+                identifier: "test".to_owned(),
+                previous: Location::new(11, 18),
+            }
+            .into(),
+            Error::DuplicateDeclaration {
+                location: Location::new(6, 20),
+                identifier: "x".to_owned(),
+                previous: Location::new(5, 17),
+            }
+            .into(),
+            Error::DuplicateDeclaration {
+                location: Location::new(8, 21),
+                identifier: "y".to_owned(),
+                previous: Location::new(6, 17),
+            }
+            .into(),
+        ])
+    );
+}
+
+#[test]
+fn error_duplicate_case() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            switch calldatasize()
+            case 1 { revert(0, 0) }
+            case 0x01 { revert(0, 0) }
+            case true { revert(0, 0) }
+            default { return(0, 0) }
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::DuplicateCase {
+                location: Location::new(7, 18),
+                value: "0x01".to_owned(),
+                previous: Location::new(6, 18),
+            }
+            .into(),
+            Error::DuplicateCase {
+                location: Location::new(8, 18),
+                value: "true".to_owned(),
+                previous: Location::new(6, 18),
+            }
+            .into(),
+        ])
+    );
+}
+
+#[test]
+fn error_literal_too_wide() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0, 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff)
+            mstore(0, 0x10000000000000000000000000000000000000000000000000000000000000000)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::LiteralTooWide {
+            location: Location::new(6, 23),
+            value: "0x10000000000000000000000000000000000000000000000000000000000000000".to_owned(),
+            bitlength: compiler_common::BITLENGTH_FIELD,
+        }
+        .into()])
+    );
+}
+
+#[test]
+fn error_invalid_literal() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0, 0x)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::InvalidLiteral {
+            location: Location::new(5, 23),
+            value: "0x".to_owned(),
+        }
+        .into()])
+    );
+}
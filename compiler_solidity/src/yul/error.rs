@@ -2,10 +2,12 @@
 //! The Yul IR error.
 //!
 
+use crate::yul::analyzer::error::Error as AnalyzerError;
 use crate::yul::lexer::error::Error as LexerError;
+use crate::yul::lexer::token::location::Location;
 use crate::yul::parser::error::Error as ParserError;
 
-#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     /// The lexer error.
     #[error("Lexical error: {0}")]
@@ -13,4 +15,31 @@ pub enum Error {
     /// The parser error.
     #[error("Syntax error: {0}")]
     Parser(#[from] ParserError),
+    /// The semantic analyzer error.
+    #[error("Semantic error: {0}")]
+    Analyzer(#[from] AnalyzerError),
+}
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Lexer(inner) => inner.location(),
+            Self::Parser(inner) => inner.location(),
+            Self::Analyzer(inner) => inner.location(),
+        }
+    }
+
+    ///
+    /// Returns the length of the erroneous source code fragment.
+    ///
+    pub fn length(&self) -> usize {
+        match self {
+            Self::Lexer(inner) => inner.length(),
+            Self::Parser(inner) => inner.length(),
+            Self::Analyzer(inner) => inner.length(),
+        }
+    }
 }
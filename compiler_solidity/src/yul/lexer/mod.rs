@@ -8,7 +8,10 @@ pub mod token;
 #[cfg(test)]
 mod tests;
 
+use std::collections::BTreeMap;
+
 use self::error::Error;
+use self::token::lexeme::comment::annotation::Annotation;
 use self::token::lexeme::comment::Comment;
 use self::token::lexeme::identifier::Identifier;
 use self::token::lexeme::literal::integer::Integer as IntegerLiteral;
@@ -16,6 +19,7 @@ use self::token::lexeme::literal::string::String as StringLiteral;
 use self::token::lexeme::symbol::Symbol;
 use self::token::lexeme::Lexeme;
 use self::token::location::Location;
+use self::token::solidity_location::SolidityLocation;
 use self::token::Token;
 
 ///
@@ -28,6 +32,10 @@ pub struct Lexer {
     offset: usize,
     /// The current location.
     location: Location,
+    /// The Solidity source code location set by the last `@src` annotation, if any.
+    solidity_location: Option<SolidityLocation>,
+    /// The Solidity source code file paths from the `@use-src` annotations.
+    source_paths: BTreeMap<usize, String>,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
 }
@@ -43,6 +51,8 @@ impl Lexer {
             input,
             offset: 0,
             location: Location::default(),
+            solidity_location: None,
+            source_paths: BTreeMap::new(),
             peeked: None,
         }
     }
@@ -69,6 +79,16 @@ impl Lexer {
             }
 
             if let Some(token) = Comment::parse(&self.input[self.offset..]) {
+                let end = std::cmp::min(self.offset + token.length, self.input.len());
+                for annotation in Annotation::parse_all(&self.input[self.offset..end]) {
+                    match annotation {
+                        Annotation::Source(location) => self.solidity_location = location,
+                        Annotation::UseSource(source_paths) => {
+                            self.source_paths.extend(source_paths)
+                        }
+                    }
+                }
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -77,6 +97,7 @@ impl Lexer {
 
             if let Some(mut token) = StringLiteral::parse(&self.input[self.offset..]) {
                 token.location = self.location;
+                token.solidity_location = self.solidity_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -85,6 +106,7 @@ impl Lexer {
 
             if let Some(mut token) = IntegerLiteral::parse(&self.input[self.offset..]) {
                 token.location = self.location;
+                token.solidity_location = self.solidity_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -93,6 +115,7 @@ impl Lexer {
 
             if let Some(mut token) = Identifier::parse(&self.input[self.offset..]) {
                 token.location = self.location;
+                token.solidity_location = self.solidity_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -101,6 +124,7 @@ impl Lexer {
 
             if let Some(mut token) = Symbol::parse(&self.input[self.offset..]) {
                 token.location = self.location;
+                token.solidity_location = self.solidity_location;
 
                 self.offset += token.length;
                 self.location.shift_right(token.length);
@@ -109,14 +133,19 @@ impl Lexer {
 
             let end = self.input[self.offset..]
                 .find(char::is_whitespace)
-                .unwrap_or(self.input.len());
-            return Err(Error::InvalidLexeme {
+                .unwrap_or(self.input.len() - self.offset);
+            let error = Error::InvalidLexeme {
                 location: self.location,
                 sequence: self.input[self.offset..self.offset + end].to_owned(),
-            });
+            };
+            self.offset += end;
+            self.location.shift_right(end);
+            return Err(error);
         }
 
-        Ok(Token::new(self.location, Lexeme::EndOfFile, 0))
+        let mut token = Token::new(self.location, Lexeme::EndOfFile, 0);
+        token.solidity_location = self.solidity_location;
+        Ok(token)
     }
 
     ///
@@ -132,4 +161,11 @@ impl Lexer {
             }
         }
     }
+
+    ///
+    /// Returns the Solidity source code file paths from the `@use-src` annotations seen so far.
+    ///
+    pub fn source_paths(&self) -> &BTreeMap<usize, String> {
+        &self.source_paths
+    }
 }
@@ -5,6 +5,7 @@
 use crate::yul::lexer::error::Error;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::solidity_location::SolidityLocation;
 use crate::yul::lexer::Lexer;
 
 #[test]
@@ -89,3 +90,70 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn error_invalid_lexeme_skipped() {
+    let mut lexer = Lexer::new("mstore # 64".to_owned());
+
+    assert!(lexer.next().is_ok());
+    assert_eq!(
+        lexer.next(),
+        Err(Error::InvalidLexeme {
+            location: Location::new(1, 8),
+            sequence: "#".to_owned(),
+        })
+    );
+    assert_eq!(
+        lexer.next().map(|token| token.location),
+        Ok(Location::new(1, 10))
+    );
+}
+
+#[test]
+fn solidity_location() {
+    let input = r#"
+/// @use-src 0:"contracts/Test.sol", 1:"contracts/Library.sol"
+object "Test" {
+    code {
+        /// @src 0:120:145  "contract Test {..."
+        mstore(64, 128)
+        /** @src 1:10:20 */ let x := callvalue()
+        /// @src -1:-1:-1
+        return(0, 0)
+    }
+}
+    "#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    let mut locations = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        match token.lexeme {
+            Lexeme::Identifier(identifier) => {
+                locations.push((identifier.inner, token.solidity_location))
+            }
+            Lexeme::EndOfFile => break,
+            _ => {}
+        }
+    }
+
+    assert_eq!(
+        locations,
+        vec![
+            (
+                "mstore".to_owned(),
+                Some(SolidityLocation::new(0, 120, 145))
+            ),
+            ("x".to_owned(), Some(SolidityLocation::new(1, 10, 20))),
+            (
+                "callvalue".to_owned(),
+                Some(SolidityLocation::new(1, 10, 20))
+            ),
+            ("return".to_owned(), None),
+        ]
+    );
+    assert_eq!(
+        lexer.source_paths().get(&1).map(String::as_str),
+        Some("contracts/Library.sol")
+    );
+}
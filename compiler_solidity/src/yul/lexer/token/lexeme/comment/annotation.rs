@@ -0,0 +1,151 @@
+//!
+//! The comment annotation.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::yul::lexer::token::solidity_location::SolidityLocation;
+
+///
+/// The comment annotation, which `solc` uses to attach the Solidity source code locations to
+/// the Yul code.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Annotation {
+    /// The `@src` annotation, setting the location of the code that follows.
+    /// The location is unset by `-1:-1:-1`, which marks the compiler-generated code.
+    Source(Option<SolidityLocation>),
+    /// The `@use-src` annotation, mapping the source code file indexes to their paths.
+    UseSource(BTreeMap<usize, String>),
+}
+
+impl Annotation {
+    /// The source code location annotation tag.
+    pub const SOURCE: &'static str = "@src";
+    /// The source code file list annotation tag.
+    pub const USE_SOURCE: &'static str = "@use-src";
+
+    ///
+    /// Parses all the annotations found in the comment, ignoring the malformed ones.
+    ///
+    /// The quoted strings, e.g. the Solidity source code snippets `solc` appends to the `@src`
+    /// annotations, are skipped.
+    ///
+    pub fn parse_all(comment: &str) -> Vec<Self> {
+        let mut annotations = Vec::new();
+
+        let mut rest = comment;
+        while let Some(position) = rest.find(|character| character == '@' || character == '"') {
+            rest = &rest[position..];
+            if rest.starts_with('"') {
+                rest = match Self::quoted_end(&rest[1..]) {
+                    Some(end) => &rest[1 + end + 1..],
+                    None => break,
+                };
+                continue;
+            }
+
+            if let Some(value) = Self::strip_tag(rest, Self::SOURCE) {
+                if let Some(location) = Self::parse_source(value) {
+                    annotations.push(Self::Source(location));
+                }
+            } else if let Some(value) = Self::strip_tag(rest, Self::USE_SOURCE) {
+                annotations.push(Self::UseSource(Self::parse_use_source(value)));
+            }
+            rest = &rest[1..];
+        }
+
+        annotations
+    }
+
+    ///
+    /// Returns the byte offset of the closing quote, skipping the escaped ones.
+    ///
+    fn quoted_end(text: &str) -> Option<usize> {
+        let mut is_escaped = false;
+        for (index, character) in text.char_indices() {
+            match character {
+                _ if is_escaped => is_escaped = false,
+                '\\' => is_escaped = true,
+                '"' => return Some(index),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    ///
+    /// Returns the annotation value, if the text starts with the `tag`.
+    ///
+    fn strip_tag<'a>(text: &'a str, tag: &str) -> Option<&'a str> {
+        text.strip_prefix(tag)
+            .filter(|value| value.starts_with(char::is_whitespace))
+            .map(str::trim_start)
+    }
+
+    ///
+    /// Parses the `@src` annotation value in the `source:start:end` format.
+    ///
+    fn parse_source(value: &str) -> Option<Option<SolidityLocation>> {
+        let value = value.split(char::is_whitespace).next().unwrap_or_default();
+        let mut parts = value.split(':').map(|part| part.parse::<isize>().ok());
+        let (source, start, end) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(Some(source)), Some(Some(start)), Some(Some(end))) => (source, start, end),
+            _ => return None,
+        };
+
+        match (
+            u32::try_from(source),
+            u32::try_from(start),
+            u32::try_from(end),
+        ) {
+            (Ok(source), Ok(start), Ok(end)) => {
+                Some(Some(SolidityLocation::new(source, start, end)))
+            }
+            _ => Some(None),
+        }
+    }
+
+    ///
+    /// Parses the `@use-src` annotation value in the `index:"path", ...` format.
+    ///
+    fn parse_use_source(mut value: &str) -> BTreeMap<usize, String> {
+        let mut source_paths = BTreeMap::new();
+
+        while let Some((index, rest)) = value.split_once(":\"") {
+            let index = match index.trim().parse::<usize>() {
+                Ok(index) => index,
+                Err(_) => break,
+            };
+            let end = match rest.find('"') {
+                Some(end) => end,
+                None => break,
+            };
+            source_paths.insert(index, rest[..end].to_owned());
+
+            value = match rest[end + 1..].trim_start().strip_prefix(',') {
+                Some(value) => value,
+                None => break,
+            };
+        }
+
+        source_paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::solidity_location::SolidityLocation;
+
+    use super::Annotation;
+
+    #[test]
+    fn source_snippet_skipped() {
+        let comment = r#"/// @src 0:120:145  "function f() { /// @src 1:1:2 \"@src\" }""#;
+
+        assert_eq!(
+            Annotation::parse_all(comment),
+            vec![Annotation::Source(Some(SolidityLocation::new(0, 120, 145)))]
+        );
+    }
+}
@@ -4,9 +4,11 @@
 
 pub mod lexeme;
 pub mod location;
+pub mod solidity_location;
 
 use self::lexeme::Lexeme;
 use self::location::Location;
+use self::solidity_location::SolidityLocation;
 
 ///
 /// The token.
@@ -21,6 +23,8 @@ pub struct Token {
     pub lexeme: Lexeme,
     /// The token length, including whitespaces.
     pub length: usize,
+    /// The Solidity source code location set by the last `@src` annotation, if any.
+    pub solidity_location: Option<SolidityLocation>,
 }
 
 impl Token {
@@ -32,6 +36,7 @@ impl Token {
             location,
             lexeme,
             length,
+            solidity_location: None,
         }
     }
 }
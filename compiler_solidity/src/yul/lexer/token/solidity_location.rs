@@ -0,0 +1,43 @@
+//!
+//! The Solidity source code location of the Yul code.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The Solidity source code location of the Yul code, set by the `@src` comment annotations.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolidityLocation {
+    /// The source code file index in the `@use-src` list.
+    pub source: u32,
+    /// The start byte offset.
+    pub start: u32,
+    /// The end byte offset.
+    pub end: u32,
+}
+
+impl SolidityLocation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(source: u32, start: u32, end: u32) -> Self {
+        Self { source, start, end }
+    }
+
+    ///
+    /// Resolves the location against the `@use-src` list, which maps the source code file
+    /// indexes to their paths.
+    ///
+    pub fn resolve(&self, source_paths: &BTreeMap<usize, String>) -> Option<SourceLocation> {
+        source_paths
+            .get(&(self.source as usize))
+            .map(|path| SourceLocation {
+                file: path.to_owned(),
+                start: self.start as isize,
+                end: self.end as isize,
+            })
+    }
+}
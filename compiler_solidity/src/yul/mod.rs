@@ -2,6 +2,7 @@
 //! The Yul IR compiling tools.
 //!
 
+pub mod analyzer;
 pub mod error;
 pub mod lexer;
 pub mod parser;
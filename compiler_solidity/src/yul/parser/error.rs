@@ -4,7 +4,7 @@
 
 use crate::yul::lexer::token::location::Location;
 
-#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     #[error("{location} Expected one of {expected:?}, found `{found}`")]
     InvalidToken {
@@ -34,3 +34,27 @@ pub enum Error {
         found: usize,
     },
 }
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::InvalidToken { location, .. } => *location,
+            Self::ReservedIdentifier { location, .. } => *location,
+            Self::InvalidNumberOfArguments { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the length of the erroneous source code fragment.
+    ///
+    pub fn length(&self) -> usize {
+        match self {
+            Self::InvalidToken { found, .. } => found.len(),
+            Self::ReservedIdentifier { identifier, .. } => identifier.len(),
+            Self::InvalidNumberOfArguments { identifier, .. } => identifier.len(),
+        }
+    }
+}
@@ -2,7 +2,10 @@
 //! The source code block.
 //!
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -28,7 +31,14 @@ impl Block {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    /// The parser recovers from the syntax and lexical errors in the block statements, pushing
+    /// them to `errors`. Only the errors it cannot recover from are returned.
+    ///
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let mut statements = Vec::new();
@@ -52,79 +62,150 @@ impl Block {
         let mut remaining = None;
 
         loop {
-            match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
-                token @ Token {
-                    lexeme: Lexeme::Keyword(_),
-                    ..
-                } => {
-                    let (statement, next) = Statement::parse(lexer, Some(token))?;
-                    remaining = next;
-                    statements.push(statement);
+            let token = match crate::yul::parser::take_or_next(remaining.take(), lexer) {
+                Ok(token) => token,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
                 }
-                token @ Token {
-                    lexeme: Lexeme::Literal(_),
-                    ..
-                } => {
-                    statements
-                        .push(Expression::parse(lexer, Some(token)).map(Statement::Expression)?);
+            };
+            match Self::parse_statement(lexer, token, errors) {
+                Ok(Some((statement, next))) => {
+                    statements.push(statement);
+                    remaining = next;
                 }
-                token @ Token {
-                    lexeme: Lexeme::Identifier(_),
-                    ..
-                } => match lexer.peek()? {
-                    Token {
-                        lexeme: Lexeme::Symbol(Symbol::Assignment),
-                        ..
-                    } => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
-                        );
-                    }
-                    Token {
-                        lexeme: Lexeme::Symbol(Symbol::Comma),
-                        ..
-                    } => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
-                        );
+                Ok(None) => break,
+                Err(error) => {
+                    let is_block_closed = matches!(
+                        error,
+                        Error::Parser(ParserError::InvalidToken { ref found, .. }) if found.as_str() == "}"
+                    );
+                    if is_block_closed {
+                        errors.push(error);
+                        break;
                     }
-                    _ => {
-                        statements.push(
-                            Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
-                        );
-                    }
-                },
-                token @ Token {
-                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                    errors.push(error);
+                    remaining = Self::synchronize(lexer, errors)?;
+                }
+            }
+        }
+
+        Ok(Self {
+            location,
+            statements,
+        })
+    }
+
+    ///
+    /// Parses a single statement starting with `token`.
+    ///
+    /// Returns `None` if `token` is the closing bracket of the block.
+    ///
+    fn parse_statement(
+        lexer: &mut Lexer,
+        token: Token,
+        errors: &mut Vec<Error>,
+    ) -> Result<Option<(Statement, Option<Token>)>, Error> {
+        match token {
+            token @ Token {
+                lexeme: Lexeme::Keyword(_),
+                ..
+            } => Statement::parse(lexer, Some(token), errors).map(Some),
+            token @ Token {
+                lexeme: Lexeme::Literal(_),
+                ..
+            } => Expression::parse(lexer, Some(token))
+                .map(|expression| Some((Statement::Expression(expression), None))),
+            token @ Token {
+                lexeme: Lexeme::Identifier(_),
+                ..
+            } => match lexer.peek()? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Assignment),
                     ..
-                } => statements.push(Block::parse(lexer, Some(token)).map(Statement::Block)?),
+                } => Assignment::parse(lexer, Some(token))
+                    .map(|assignment| Some((Statement::Assignment(assignment), None))),
                 Token {
-                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
                     ..
-                } => break,
-                token => {
+                } => Assignment::parse(lexer, Some(token))
+                    .map(|assignment| Some((Statement::Assignment(assignment), None))),
+                _ => Expression::parse(lexer, Some(token))
+                    .map(|expression| Some((Statement::Expression(expression), None))),
+            },
+            token @ Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => Block::parse(lexer, Some(token), errors)
+                .map(|block| Some((Statement::Block(block), None))),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                ..
+            } => Ok(None),
+            token => Err(ParserError::InvalidToken {
+                location: token.location,
+                expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                found: token.lexeme.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    ///
+    /// Skips the tokens after a syntax error until a point where the parsing can be resumed.
+    ///
+    /// The synchronization points are the `function` and `let` keywords, which are returned, and
+    /// the closing bracket of the current block, which is left in the lexer for the caller. The
+    /// end of file cannot be recovered from, so it is returned as an error. The lexical errors in
+    /// the skipped code are pushed to `errors`.
+    ///
+    fn synchronize(lexer: &mut Lexer, errors: &mut Vec<Error>) -> Result<Option<Token>, Error> {
+        let mut depth = 0;
+        loop {
+            let token = match lexer.peek() {
+                Ok(token) => token,
+                Err(error) => {
+                    errors.push(error.into());
+                    continue;
+                }
+            };
+            match token.lexeme {
+                Lexeme::Symbol(Symbol::BracketCurlyRight) if depth == 0 => return Ok(None),
+                Lexeme::EndOfFile => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
-                        expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                        expected: vec!["}"],
                         found: token.lexeme.to_string(),
                     }
-                    .into());
+                    .into())
                 }
+                _ => {}
             }
-        }
 
-        Ok(Self {
-            location,
-            statements,
-        })
+            let token = lexer.next()?;
+            match token.lexeme {
+                Lexeme::Keyword(Keyword::Function) | Lexeme::Keyword(Keyword::Let)
+                    if depth == 0 =>
+                {
+                    return Ok(Some(token))
+                }
+                Lexeme::Symbol(Symbol::BracketCurlyLeft) => depth += 1,
+                Lexeme::Symbol(Symbol::BracketCurlyRight) => depth -= 1,
+                _ => {}
+            }
+        }
     }
 }
 
-impl<D> compiler_llvm_context::WriteLLVM<D> for Block
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Block
 where
     D: compiler_llvm_context::Dependency,
 {
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         let current_function = context.function().to_owned();
         let current_block = context.basic_block();
 
@@ -142,7 +223,7 @@ where
         }
 
         for function in functions.into_iter() {
-            function.into_llvm(context)?;
+            function.into_llvm_debug(context, debug_info)?;
         }
 
         context.set_function(current_function.clone());
@@ -152,18 +233,25 @@ where
                 break;
             }
 
+            if let Some(debug_info) = debug_info {
+                let location = statement.location();
+                debug_info.set_location(context, None, location.line, location.column);
+            }
+
             match statement {
                 Statement::Block(block) => {
-                    block.into_llvm(context)?;
+                    block.into_llvm_debug(context, debug_info)?;
                 }
                 Statement::Expression(expression) => {
                     expression.into_llvm(context)?;
                 }
                 Statement::VariableDeclaration(statement) => statement.into_llvm(context)?,
                 Statement::Assignment(statement) => statement.into_llvm(context)?,
-                Statement::IfConditional(statement) => statement.into_llvm(context)?,
-                Statement::Switch(statement) => statement.into_llvm(context)?,
-                Statement::ForLoop(statement) => statement.into_llvm(context)?,
+                Statement::IfConditional(statement) => {
+                    statement.into_llvm_debug(context, debug_info)?
+                }
+                Statement::Switch(statement) => statement.into_llvm_debug(context, debug_info)?,
+                Statement::ForLoop(statement) => statement.into_llvm_debug(context, debug_info)?,
                 Statement::Continue(_location) => {
                     context.build_unconditional_branch(context.r#loop().continue_block);
                     break;
@@ -190,6 +278,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::yul::lexer::error::Error as LexerError;
     use crate::yul::lexer::token::location::Location;
     use crate::yul::lexer::Lexer;
     use crate::yul::parser::error::Error;
@@ -261,4 +350,119 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_multiple_recovered() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            let x := )
+            mstore(0, x)
+            let y := :=
+            function test(a b) {}
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse_all(&mut lexer);
+        assert_eq!(
+            result,
+            Err(vec![
+                Error::InvalidToken {
+                    location: Location::new(5, 22),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ")".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(7, 22),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(8, 29),
+                    expected: vec![")"],
+                    found: "b".to_owned(),
+                }
+                .into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn error_recovered_closing_bracket() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            {
+                let x :=
+            }
+            let y := )
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse_all(&mut lexer);
+        assert_eq!(
+            result,
+            Err(vec![
+                Error::InvalidToken {
+                    location: Location::new(7, 13),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: "}".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(8, 22),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ")".to_owned(),
+                }
+                .into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn error_lexical_recovered() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            #
+            mstore(0, 1)
+            let y := :=
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse_all(&mut lexer);
+        assert_eq!(
+            result,
+            Err(vec![
+                LexerError::InvalidLexeme {
+                    location: Location::new(5, 13),
+                    sequence: "#".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(7, 22),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+            ])
+        );
+    }
 }
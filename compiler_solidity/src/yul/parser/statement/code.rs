@@ -2,6 +2,8 @@
 //! The YUL code.
 //!
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -23,10 +25,30 @@ pub struct Code {
 }
 
 impl Code {
+    /// The deploy code function name.
+    pub const DEPLOY_FUNCTION_NAME: &'static str = "code_deploy";
+
+    /// The runtime code function name.
+    pub const RUNTIME_FUNCTION_NAME: &'static str = "code_runtime";
+
+    ///
+    /// Returns the name of the function the code of `code_type` is translated into.
+    ///
+    pub fn function_name(code_type: compiler_llvm_context::CodeType) -> &'static str {
+        match code_type {
+            compiler_llvm_context::CodeType::Deploy => Self::DEPLOY_FUNCTION_NAME,
+            compiler_llvm_context::CodeType::Runtime => Self::RUNTIME_FUNCTION_NAME,
+        }
+    }
+
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let location = match token {
@@ -45,18 +67,25 @@ impl Code {
             }
         };
 
-        let block = Block::parse(lexer, None)?;
+        let block = Block::parse(lexer, None, errors)?;
 
         Ok(Self { location, block })
     }
 }
 
-impl<D> compiler_llvm_context::WriteLLVM<D> for Code
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Code
 where
     D: compiler_llvm_context::Dependency,
 {
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        self.block.into_llvm(context)?;
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
+        if let Some(debug_info) = debug_info {
+            debug_info.set_function_location(context, self.location.line);
+        }
+        self.block.into_llvm_debug(context, debug_info)?;
 
         Ok(())
     }
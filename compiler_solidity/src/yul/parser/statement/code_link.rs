@@ -0,0 +1,43 @@
+//!
+//! The Yul code function link.
+//!
+
+use crate::yul::parser::statement::code::Code;
+
+///
+/// The Yul code function link.
+///
+/// The link calls the function the deploy or runtime code is translated into from the
+/// corresponding code function of the LLVM IR generator context.
+///
+#[derive(Debug, Clone)]
+pub struct CodeLink {
+    /// The code part type.
+    pub code_type: compiler_llvm_context::CodeType,
+}
+
+impl CodeLink {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code_type: compiler_llvm_context::CodeType) -> Self {
+        Self { code_type }
+    }
+}
+
+impl<D> compiler_llvm_context::WriteLLVM<D> for CodeLink
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let function_name = Code::function_name(self.code_type);
+        let target = context
+            .functions
+            .get(function_name)
+            .expect("Always exists")
+            .value;
+        context.build_invoke(target, &[], format!("call_link_{}", function_name).as_str());
+
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 //! The for-loop statement.
 //!
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
@@ -30,17 +32,21 @@ impl ForLoop {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
         let location = token.location;
 
-        let initializer = Block::parse(lexer, Some(token))?;
+        let initializer = Block::parse(lexer, Some(token), errors)?;
 
         let condition = Expression::parse(lexer, None)?;
 
-        let finalizer = Block::parse(lexer, None)?;
+        let finalizer = Block::parse(lexer, None, errors)?;
 
-        let body = Block::parse(lexer, None)?;
+        let body = Block::parse(lexer, None, errors)?;
 
         Ok(Self {
             location,
@@ -52,12 +58,16 @@ impl ForLoop {
     }
 }
 
-impl<D> compiler_llvm_context::WriteLLVM<D> for ForLoop
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for ForLoop
 where
     D: compiler_llvm_context::Dependency,
 {
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        self.initializer.into_llvm(context)?;
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
+        self.initializer.into_llvm_debug(context, debug_info)?;
 
         let condition_block = context.append_basic_block("for_condition");
         let body_block = context.append_basic_block("for_body");
@@ -88,11 +98,11 @@ where
         context.push_loop(body_block, increment_block, join_block);
 
         context.set_basic_block(body_block);
-        self.body.into_llvm(context)?;
+        self.body.into_llvm_debug(context, debug_info)?;
         context.build_unconditional_branch(increment_block);
 
         context.set_basic_block(increment_block);
-        self.finalizer.into_llvm(context)?;
+        self.finalizer.into_llvm_debug(context, debug_info)?;
         context.build_unconditional_branch(condition_block);
 
         context.pop_loop();
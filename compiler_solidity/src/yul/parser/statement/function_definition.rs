@@ -4,6 +4,8 @@
 
 use inkwell::types::BasicType;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -40,7 +42,11 @@ impl FunctionDefinition {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let (location, identifier) = match token {
@@ -153,7 +159,7 @@ impl FunctionDefinition {
             }
         };
 
-        let body = Block::parse(lexer, next)?;
+        let body = Block::parse(lexer, next, errors)?;
 
         Ok(Self {
             location,
@@ -208,7 +214,20 @@ where
         Ok(())
     }
 
-    fn into_llvm(mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for FunctionDefinition
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        mut self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         let function = context
             .functions
             .get(self.identifier.as_str())
@@ -217,6 +236,9 @@ where
         context.set_function(function.clone());
 
         context.set_basic_block(function.entry_block);
+        if let Some(debug_info) = debug_info {
+            debug_info.set_function_location(context, self.location.line);
+        }
         let r#return = match function.r#return {
             Some(r#return) => {
                 for (index, identifier) in self.result.into_iter().enumerate() {
@@ -293,7 +315,7 @@ where
             );
         }
 
-        self.body.into_llvm(context)?;
+        self.body.into_llvm_debug(context, debug_info)?;
         match context
             .basic_block()
             .get_last_instruction()
@@ -322,6 +344,9 @@ where
                 context.build_return(Some(&return_pointer));
             }
         }
+        if let Some(debug_info) = debug_info {
+            debug_info.unset_location(context);
+        }
 
         Ok(())
     }
@@ -2,6 +2,8 @@
 //! The if-conditional statement.
 //!
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
@@ -26,13 +28,17 @@ impl IfConditional {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
         let location = token.location;
 
         let condition = Expression::parse(lexer, Some(token))?;
 
-        let block = Block::parse(lexer, None)?;
+        let block = Block::parse(lexer, None, errors)?;
 
         Ok(Self {
             location,
@@ -42,11 +48,15 @@ impl IfConditional {
     }
 }
 
-impl<D> compiler_llvm_context::WriteLLVM<D> for IfConditional
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for IfConditional
 where
     D: compiler_llvm_context::Dependency,
 {
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         let condition = self
             .condition
             .into_llvm(context)?
@@ -68,7 +78,7 @@ where
         let join_block = context.append_basic_block("if_join");
         context.build_conditional_branch(condition, main_block, join_block);
         context.set_basic_block(main_block);
-        self.block.into_llvm(context)?;
+        self.block.into_llvm_debug(context, debug_info)?;
         context.build_unconditional_branch(join_block);
         context.set_basic_block(join_block);
 
@@ -5,6 +5,7 @@
 pub mod assignment;
 pub mod block;
 pub mod code;
+pub mod code_link;
 pub mod expression;
 pub mod for_loop;
 pub mod function_definition;
@@ -72,6 +73,7 @@ impl Statement {
     pub fn parse(
         lexer: &mut Lexer,
         initial: Option<Token>,
+        errors: &mut Vec<Error>,
     ) -> Result<(Self, Option<Token>), Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
@@ -79,16 +81,19 @@ impl Statement {
             token @ Token {
                 lexeme: Lexeme::Keyword(Keyword::Object),
                 ..
-            } => Ok((Statement::Object(Object::parse(lexer, Some(token))?), None)),
+            } => Ok((
+                Statement::Object(Object::parse_recovering(lexer, Some(token), errors)?),
+                None,
+            )),
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Code),
                 ..
-            } => Ok((Statement::Code(Code::parse(lexer, None)?), None)),
+            } => Ok((Statement::Code(Code::parse(lexer, None, errors)?), None)),
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Function),
                 ..
             } => Ok((
-                Statement::FunctionDefinition(FunctionDefinition::parse(lexer, None)?),
+                Statement::FunctionDefinition(FunctionDefinition::parse(lexer, None, errors)?),
                 None,
             )),
             Token {
@@ -102,17 +107,20 @@ impl Statement {
                 lexeme: Lexeme::Keyword(Keyword::If),
                 ..
             } => Ok((
-                Statement::IfConditional(IfConditional::parse(lexer, None)?),
+                Statement::IfConditional(IfConditional::parse(lexer, None, errors)?),
                 None,
             )),
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Switch),
                 ..
-            } => Ok((Statement::Switch(Switch::parse(lexer, None)?), None)),
+            } => Ok((Statement::Switch(Switch::parse(lexer, None, errors)?), None)),
             Token {
                 lexeme: Lexeme::Keyword(Keyword::For),
                 ..
-            } => Ok((Statement::ForLoop(ForLoop::parse(lexer, None)?), None)),
+            } => Ok((
+                Statement::ForLoop(ForLoop::parse(lexer, None, errors)?),
+                None,
+            )),
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Continue),
                 location,
@@ -2,8 +2,11 @@
 //! The YUL object.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::literal::Literal;
@@ -14,6 +17,7 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::code_link::CodeLink;
 
 ///
 /// The upper-level YUL object, representing the deploy code.
@@ -24,6 +28,8 @@ pub struct Object {
     pub location: Location,
     /// The identifier.
     pub identifier: String,
+    /// The Solidity source code file paths from the `@use-src` annotation, by their indexes.
+    pub source_paths: BTreeMap<usize, String>,
     /// The code.
     pub code: Code,
     /// The optional inner object, representing the runtime code.
@@ -38,7 +44,41 @@ impl Object {
     ///
     /// The element parser.
     ///
+    /// The parser recovers from syntax errors inside blocks, but the first error still decides
+    /// the result. Use `parse_all` to get all the errors.
+    ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+        let mut errors = Vec::new();
+        let result = Self::parse_recovering(lexer, initial, &mut errors);
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => result,
+        }
+    }
+
+    ///
+    /// Parses the upper-level object, returning all the errors found by the recovering parser.
+    ///
+    pub fn parse_all(lexer: &mut Lexer) -> Result<Self, Vec<Error>> {
+        let mut errors = Vec::new();
+        match Self::parse_recovering(lexer, None, &mut errors) {
+            Ok(object) if errors.is_empty() => Ok(object),
+            Ok(_object) => Err(errors),
+            Err(error) => {
+                errors.push(error);
+                Err(errors)
+            }
+        }
+    }
+
+    ///
+    /// The element parser, which pushes the errors recovered from to `errors`.
+    ///
+    pub fn parse_recovering(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let location = match token {
@@ -56,6 +96,7 @@ impl Object {
                 .into());
             }
         };
+        let source_paths = lexer.source_paths().to_owned();
 
         let identifier = match lexer.next()? {
             Token {
@@ -88,7 +129,7 @@ impl Object {
             }
         }
 
-        let code = Code::parse(lexer, None)?;
+        let code = Code::parse(lexer, None, errors)?;
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
 
@@ -98,7 +139,7 @@ impl Object {
                     lexeme: Lexeme::Keyword(Keyword::Object),
                     ..
                 } => {
-                    let mut object = Self::parse(lexer, None)?;
+                    let mut object = Self::parse_recovering(lexer, None, errors)?;
                     factory_dependencies.extend(object.factory_dependencies.drain());
                     Some(Box::new(object))
                 }
@@ -128,7 +169,7 @@ impl Object {
                     lexeme: Lexeme::Keyword(Keyword::Object),
                     ..
                 } => {
-                    let dependency = Self::parse(lexer, Some(token))?;
+                    let dependency = Self::parse_recovering(lexer, Some(token), errors)?;
                     factory_dependencies.insert(dependency.identifier);
                 }
                 Token {
@@ -152,6 +193,7 @@ impl Object {
         Ok(Self {
             location,
             identifier,
+            source_paths,
             code,
             inner_object,
             factory_dependencies,
@@ -182,14 +224,70 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        if self.identifier.ends_with("_deployed") {
-            compiler_llvm_context::RuntimeCodeFunction::new(self.code).into_llvm(context)?;
+        self.into_llvm_debug(context, None)
+    }
+}
+
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Object
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
+        let code_type = if self.identifier.ends_with("_deployed") {
+            compiler_llvm_context::CodeType::Runtime
         } else {
-            compiler_llvm_context::DeployCodeFunction::new(self.code).into_llvm(context)?;
+            compiler_llvm_context::CodeType::Deploy
+        };
+
+        let function_name = Code::function_name(code_type);
+        let function_type = context.function_type(0, vec![]);
+        context.add_function(
+            function_name,
+            function_type,
+            Some(inkwell::module::Linkage::Private),
+        );
+        let function = context
+            .functions
+            .get(function_name)
+            .cloned()
+            .expect("Always exists");
+        context.set_function(function.clone());
+        context.set_basic_block(function.entry_block);
+        context.set_code_type(code_type);
+
+        self.code.into_llvm_debug(context, debug_info)?;
+        match context
+            .basic_block()
+            .get_last_instruction()
+            .map(|instruction| instruction.get_opcode())
+        {
+            Some(inkwell::values::InstructionOpcode::Br) => {}
+            Some(inkwell::values::InstructionOpcode::Switch) => {}
+            _ => context.build_unconditional_branch(context.function().return_block),
+        }
+        context.set_basic_block(context.function().return_block);
+        context.build_return(None);
+        if let Some(debug_info) = debug_info {
+            debug_info.unset_location(context);
+        }
+
+        match code_type {
+            compiler_llvm_context::CodeType::Deploy => {
+                compiler_llvm_context::DeployCodeFunction::new(CodeLink::new(code_type))
+                    .into_llvm(context)?
+            }
+            compiler_llvm_context::CodeType::Runtime => {
+                compiler_llvm_context::RuntimeCodeFunction::new(CodeLink::new(code_type))
+                    .into_llvm(context)?
+            }
         }
 
         if let Some(object) = self.inner_object {
-            object.into_llvm(context)?;
+            object.into_llvm_debug(context, debug_info)?;
         }
 
         Ok(())
@@ -330,4 +428,46 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_multiple_recovered_deploy_and_runtime() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            let x := )
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let y := :=
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse_all(&mut lexer);
+        assert_eq!(
+            result,
+            Err(vec![
+                Error::InvalidToken {
+                    location: Location::new(5, 22),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ")".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(12, 26),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+            ])
+        );
+    }
 }
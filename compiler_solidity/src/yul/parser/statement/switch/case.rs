@@ -28,7 +28,11 @@ impl Case {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let (location, literal) = match token {
@@ -47,7 +51,7 @@ impl Case {
             }
         };
 
-        let block = Block::parse(lexer, None)?;
+        let block = Block::parse(lexer, None, errors)?;
 
         Ok(Self {
             location,
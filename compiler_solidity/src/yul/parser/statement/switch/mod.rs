@@ -4,6 +4,8 @@
 
 pub mod case;
 
+use crate::debug_info::write_debug_llvm::WriteDebugLLVM;
+use crate::debug_info::DebugInfo;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -47,7 +49,11 @@ impl Switch {
     ///
     /// The element parser.
     ///
-    pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+    pub fn parse(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Self, Error> {
         let mut token = crate::yul::parser::take_or_next(initial, lexer)?;
         let location = token.location;
         let mut state = State::CaseOrDefaultKeyword;
@@ -80,12 +86,12 @@ impl Switch {
                 },
                 State::CaseBlock => {
                     lexer.next()?;
-                    cases.push(Case::parse(lexer, None)?);
+                    cases.push(Case::parse(lexer, None, errors)?);
                     state = State::CaseOrDefaultKeyword;
                 }
                 State::DefaultBlock => {
                     lexer.next()?;
-                    default = Some(Block::parse(lexer, None)?);
+                    default = Some(Block::parse(lexer, None, errors)?);
                     break;
                 }
             }
@@ -109,14 +115,18 @@ impl Switch {
     }
 }
 
-impl<D> compiler_llvm_context::WriteLLVM<D> for Switch
+impl<'ctx, D> WriteDebugLLVM<'ctx, D> for Switch
 where
     D: compiler_llvm_context::Dependency,
 {
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm_debug(
+        self,
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        debug_info: Option<&DebugInfo<'ctx>>,
+    ) -> anyhow::Result<()> {
         if self.cases.is_empty() {
             if let Some(block) = self.default {
-                block.into_llvm(context)?;
+                block.into_llvm_debug(context, debug_info)?;
             }
             return Ok(());
         }
@@ -131,7 +141,7 @@ where
             let expression_block = context
                 .append_basic_block(format!("switch_case_branch_{}_block", index + 1).as_str());
             context.set_basic_block(expression_block);
-            case.block.into_llvm(context)?;
+            case.block.into_llvm_debug(context, debug_info)?;
             context.build_unconditional_branch(join_block);
 
             branches.push((constant.into_int_value(), expression_block));
@@ -141,7 +151,7 @@ where
             Some(default) => {
                 let default_block = context.append_basic_block("switch_default_block");
                 context.set_basic_block(default_block);
-                default.into_llvm(context)?;
+                default.into_llvm_debug(context, debug_info)?;
                 context.build_unconditional_branch(join_block);
                 default_block
             }
@@ -0,0 +1,58 @@
+//!
+//! The zkEVM instruction execution condition.
+//!
+
+///
+/// The zkEVM instruction execution condition.
+///
+/// The variants are ordered as in the instruction encoding.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    /// The instruction is always executed.
+    Always,
+    /// Executed if the `GT` flag is set.
+    Gt,
+    /// Executed if the `LT` flag is set.
+    Lt,
+    /// Executed if the `EQ` flag is set.
+    Eq,
+    /// Executed if either the `GT` or `EQ` flag is set.
+    Ge,
+    /// Executed if either the `LT` or `EQ` flag is set.
+    Le,
+    /// Executed if the `EQ` flag is not set.
+    Ne,
+    /// Executed if either the `GT` or `LT` flag is set.
+    GtOrLt,
+}
+
+impl From<u64> for Condition {
+    fn from(value: u64) -> Self {
+        match value & 0b111 {
+            0 => Self::Always,
+            1 => Self::Gt,
+            2 => Self::Lt,
+            3 => Self::Eq,
+            4 => Self::Ge,
+            5 => Self::Le,
+            6 => Self::Ne,
+            _ => Self::GtOrLt,
+        }
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => Ok(()),
+            Self::Gt => write!(f, ".gt"),
+            Self::Lt => write!(f, ".lt"),
+            Self::Eq => write!(f, ".eq"),
+            Self::Ge => write!(f, ".ge"),
+            Self::Le => write!(f, ".le"),
+            Self::Ne => write!(f, ".ne"),
+            Self::GtOrLt => write!(f, ".gtlt"),
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//!
+//! The zkEVM instruction.
+//!
+
+pub mod condition;
+
+use zkevm_assembly::zkevm_opcode_defs::BinopOpcode;
+use zkevm_assembly::zkevm_opcode_defs::ContextOpcode;
+use zkevm_assembly::zkevm_opcode_defs::FarCallOpcode;
+use zkevm_assembly::zkevm_opcode_defs::ImmMemHandlerFlags;
+use zkevm_assembly::zkevm_opcode_defs::LogOpcode;
+use zkevm_assembly::zkevm_opcode_defs::Opcode;
+use zkevm_assembly::zkevm_opcode_defs::OpcodeVariant;
+use zkevm_assembly::zkevm_opcode_defs::Operand;
+use zkevm_assembly::zkevm_opcode_defs::PtrOpcode;
+use zkevm_assembly::zkevm_opcode_defs::RegOrImmFlags;
+use zkevm_assembly::zkevm_opcode_defs::RetOpcode;
+use zkevm_assembly::zkevm_opcode_defs::ShiftOpcode;
+use zkevm_assembly::zkevm_opcode_defs::UMAOpcode;
+
+use super::layout::Layout;
+
+use self::condition::Condition;
+
+///
+/// The zkEVM instruction.
+///
+/// The 64-bit instruction encoding consists of, from the least significant bits:
+/// the 11-bit opcode variant index, the 3-bit condition at bit 13, four 4-bit registers
+/// `src0`, `src1`, `dst0`, and `dst1`, and two 16-bit immediates `imm0` and `imm1`.
+///
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    /// The opcode variant with the operand addressing modes and flags.
+    pub variant: OpcodeVariant,
+    /// The execution condition.
+    pub condition: Condition,
+    /// The first source register.
+    pub src0: u8,
+    /// The second source register.
+    pub src1: u8,
+    /// The first destination register.
+    pub dst0: u8,
+    /// The second destination register.
+    pub dst1: u8,
+    /// The first immediate, used by the source operand addressing and code addresses.
+    pub imm0: u16,
+    /// The second immediate, used by the destination operand addressing and code addresses.
+    pub imm1: u16,
+}
+
+impl Instruction {
+    /// The instruction size in bytes.
+    pub const SIZE: usize = 8;
+
+    ///
+    /// Decodes the instruction.
+    ///
+    pub fn new(raw: u64) -> Self {
+        Self {
+            variant: zkevm_assembly::zkevm_opcode_defs::OPCODES_TABLE[(raw & 0x7ff) as usize],
+            condition: Condition::from(raw >> 13),
+            src0: ((raw >> 16) & 0xf) as u8,
+            src1: ((raw >> 20) & 0xf) as u8,
+            dst0: ((raw >> 24) & 0xf) as u8,
+            dst1: ((raw >> 28) & 0xf) as u8,
+            imm0: (raw >> 32) as u16,
+            imm1: (raw >> 48) as u16,
+        }
+    }
+
+    ///
+    /// Returns the code page word read by the source operand, if it is a constant.
+    ///
+    pub fn constant_address(&self) -> Option<usize> {
+        match self.variant.src0_operand_type {
+            Operand::Full(ImmMemHandlerFlags::UseCodePage) if self.src0 == 0 => {
+                Some(self.imm0 as usize)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the code addresses referenced by the instruction.
+    ///
+    pub fn code_targets(&self) -> Vec<usize> {
+        match self.variant.opcode {
+            Opcode::Jump(_) if self.is_source_immediate() => vec![self.imm0 as usize],
+            Opcode::NearCall(_) => vec![self.imm0 as usize, self.imm1 as usize],
+            Opcode::FarCall(_) => vec![self.imm0 as usize],
+            Opcode::Ret(_) if self.variant.flags[0] => vec![self.imm0 as usize],
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Returns the constant compared with, if the instruction is a flag-setting subtraction.
+    ///
+    pub fn compared_constant(&self) -> Option<usize> {
+        match self.variant.opcode {
+            Opcode::Sub(_) if self.variant.flags[1] => self.constant_address(),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the condition and destination, if the instruction is a conditional jump.
+    ///
+    pub fn conditional_jump(&self) -> Option<(Condition, usize)> {
+        match self.variant.opcode {
+            Opcode::Jump(_)
+                if self.condition != Condition::Always && self.is_source_immediate() =>
+            {
+                Some((self.condition, self.imm0 as usize))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the assembly text of the instruction.
+    ///
+    pub fn to_text(&self, layout: &Layout) -> String {
+        let operands = self.operands(layout);
+        if operands.is_empty() {
+            self.mnemonic()
+        } else {
+            format!("{}\t{}", self.mnemonic(), operands.join(", "))
+        }
+    }
+
+    ///
+    /// Returns the mnemonic with the modifiers, condition, and flag-setting suffix.
+    ///
+    fn mnemonic(&self) -> String {
+        let [flag0, flag1] = self.variant.flags;
+
+        let (name, modifiers, set_flags) = match self.variant.opcode {
+            Opcode::Invalid(_) => ("invalid", vec![], false),
+            Opcode::Nop(_) => ("nop", vec![], false),
+            Opcode::Add(_) => ("add", vec![(flag0, "s")], flag1),
+            Opcode::Sub(_) => ("sub", vec![(flag0, "s")], flag1),
+            Opcode::Mul(_) => ("mul", vec![(flag0, "s")], flag1),
+            Opcode::Div(_) => ("div", vec![(flag0, "s")], flag1),
+            Opcode::Jump(_) => ("jump", vec![], false),
+            Opcode::Context(opcode) => (
+                match opcode {
+                    ContextOpcode::This => "context.this",
+                    ContextOpcode::Caller => "context.caller",
+                    ContextOpcode::CodeAddress => "context.code_source",
+                    ContextOpcode::Meta => "context.meta",
+                    ContextOpcode::ErgsLeft => "context.ergs_left",
+                    ContextOpcode::Sp => "context.sp",
+                    ContextOpcode::GetContextU128 => "context.get_context_u128",
+                    ContextOpcode::SetContextU128 => "context.set_context_u128",
+                    ContextOpcode::SetErgsPerPubdataByte => "context.set_ergs_per_pubdata",
+                    ContextOpcode::IncrementTxNumber => "context.inc_tx_num",
+                },
+                vec![],
+                false,
+            ),
+            Opcode::Shift(opcode) => (
+                match opcode {
+                    ShiftOpcode::Shl => "shl",
+                    ShiftOpcode::Shr => "shr",
+                    ShiftOpcode::Rol => "rol",
+                    ShiftOpcode::Ror => "ror",
+                },
+                vec![(flag0, "s")],
+                flag1,
+            ),
+            Opcode::Binop(opcode) => (
+                match opcode {
+                    BinopOpcode::And => "and",
+                    BinopOpcode::Or => "or",
+                    BinopOpcode::Xor => "xor",
+                },
+                vec![(flag0, "s")],
+                flag1,
+            ),
+            Opcode::Ptr(opcode) => (
+                match opcode {
+                    PtrOpcode::Add => "ptr.add",
+                    PtrOpcode::Sub => "ptr.sub",
+                    PtrOpcode::Pack => "ptr.pack",
+                    PtrOpcode::Shrink => "ptr.shrink",
+                },
+                vec![(flag0, "s")],
+                false,
+            ),
+            Opcode::NearCall(_) => ("near_call", vec![], false),
+            Opcode::Log(opcode) => match opcode {
+                LogOpcode::StorageRead => ("sload", vec![], false),
+                LogOpcode::StorageWrite => ("sstore", vec![], false),
+                LogOpcode::ToL1Message => ("to_l1", vec![(flag0, "first")], false),
+                LogOpcode::Event => ("event", vec![(flag0, "first")], false),
+                LogOpcode::PrecompileCall => ("precompile", vec![], false),
+            },
+            Opcode::FarCall(opcode) => (
+                match opcode {
+                    FarCallOpcode::Normal => "far_call",
+                    FarCallOpcode::Delegate => "far_call.delegate",
+                    FarCallOpcode::Mimic => "far_call.mimic",
+                },
+                vec![(flag0, "static"), (flag1, "shard")],
+                false,
+            ),
+            Opcode::Ret(opcode) => (
+                match opcode {
+                    RetOpcode::Ok => "ret.ok",
+                    RetOpcode::Revert => "ret.revert",
+                    RetOpcode::Panic => "ret.panic",
+                },
+                vec![(flag0, "to_label")],
+                false,
+            ),
+            Opcode::UMA(opcode) => (
+                match opcode {
+                    UMAOpcode::HeapRead => "ld.1",
+                    UMAOpcode::HeapWrite => "st.1",
+                    UMAOpcode::AuxHeapRead => "ld.2",
+                    UMAOpcode::AuxHeapWrite => "st.2",
+                    UMAOpcode::FatPointerRead => "ld",
+                },
+                vec![(flag0, "inc")],
+                false,
+            ),
+        };
+
+        let mut mnemonic = name.to_owned();
+        for (_is_set, modifier) in modifiers.into_iter().filter(|(is_set, _)| *is_set) {
+            mnemonic.push('.');
+            mnemonic.push_str(modifier);
+        }
+        mnemonic.push_str(self.condition.to_string().as_str());
+        if set_flags {
+            mnemonic.push('!');
+        }
+        mnemonic
+    }
+
+    ///
+    /// Returns the operands in the assembly order.
+    ///
+    fn operands(&self, layout: &Layout) -> Vec<String> {
+        let src0 = || self.source(layout);
+        let src1 = || Self::register(self.src1);
+        let dst0 = || self.destination();
+        let dst1 = || Self::register(self.dst1);
+
+        match self.variant.opcode {
+            Opcode::Invalid(_) => vec![],
+            Opcode::Nop(_) => {
+                let mut operands = Vec::with_capacity(2);
+                if !self.is_source_trivial() {
+                    operands.push(src0());
+                }
+                if !self.is_destination_trivial() {
+                    operands.push(dst0());
+                }
+                operands
+            }
+            Opcode::Add(_)
+            | Opcode::Sub(_)
+            | Opcode::Shift(_)
+            | Opcode::Binop(_)
+            | Opcode::Ptr(_) => vec![src0(), src1(), dst0()],
+            Opcode::Mul(_) | Opcode::Div(_) => vec![src0(), src1(), dst0(), dst1()],
+            Opcode::Jump(_) if self.is_source_immediate() => {
+                vec![layout.code_operand(self.imm0 as usize)]
+            }
+            Opcode::Jump(_) => vec![src0()],
+            Opcode::Context(ContextOpcode::SetContextU128)
+            | Opcode::Context(ContextOpcode::SetErgsPerPubdataByte) => vec![src0()],
+            Opcode::Context(ContextOpcode::IncrementTxNumber) => vec![],
+            Opcode::Context(_) => vec![dst0()],
+            Opcode::Log(LogOpcode::StorageRead) => vec![src0(), dst0()],
+            Opcode::Log(LogOpcode::PrecompileCall) => vec![src0(), src1(), dst0()],
+            Opcode::Log(_) => vec![src0(), src1()],
+            Opcode::NearCall(_) => vec![
+                src0(),
+                layout.code_operand(self.imm0 as usize),
+                layout.code_operand(self.imm1 as usize),
+            ],
+            Opcode::FarCall(_) => vec![src0(), src1(), layout.code_operand(self.imm0 as usize)],
+            Opcode::Ret(opcode) => {
+                let mut operands = Vec::with_capacity(2);
+                if !matches!(opcode, RetOpcode::Panic) {
+                    operands.push(src0());
+                }
+                if self.variant.flags[0] {
+                    operands.push(layout.code_operand(self.imm0 as usize));
+                }
+                operands
+            }
+            Opcode::UMA(UMAOpcode::HeapWrite) | Opcode::UMA(UMAOpcode::AuxHeapWrite) => {
+                let mut operands = vec![src0(), src1()];
+                if self.variant.flags[0] {
+                    operands.push(dst0());
+                }
+                operands
+            }
+            Opcode::UMA(_) => {
+                let mut operands = vec![src0(), dst0()];
+                if self.variant.flags[0] {
+                    operands.push(dst1());
+                }
+                operands
+            }
+        }
+    }
+
+    ///
+    /// Returns the first source operand according to its addressing mode.
+    ///
+    fn source(&self, layout: &Layout) -> String {
+        match self.variant.src0_operand_type {
+            Operand::RegOrImm(RegOrImmFlags::UseImm16Only)
+            | Operand::Full(ImmMemHandlerFlags::UseImm16Only) => self.imm0.to_string(),
+            Operand::Full(ImmMemHandlerFlags::UseStackWithPushPop) => {
+                format!("stack-=[{}]", Self::address(self.src0, self.imm0))
+            }
+            Operand::Full(ImmMemHandlerFlags::UseStackWithOffset) => {
+                format!("stack-[{}]", Self::address(self.src0, self.imm0))
+            }
+            Operand::Full(ImmMemHandlerFlags::UseAbsoluteOnStack) => {
+                format!("stack[{}]", Self::address(self.src0, self.imm0))
+            }
+            Operand::Full(ImmMemHandlerFlags::UseCodePage) if self.src0 == 0 => {
+                layout.constant_operand(self.imm0 as usize)
+            }
+            Operand::Full(ImmMemHandlerFlags::UseCodePage) => {
+                format!("code[{}]", Self::address(self.src0, self.imm0))
+            }
+            Operand::RegOnly
+            | Operand::RegOrImm(RegOrImmFlags::UseRegOnly)
+            | Operand::Full(ImmMemHandlerFlags::UseRegOnly) => Self::register(self.src0),
+        }
+    }
+
+    ///
+    /// Returns the first destination operand according to its addressing mode.
+    ///
+    fn destination(&self) -> String {
+        match self.variant.dst0_operand_type {
+            Operand::Full(ImmMemHandlerFlags::UseStackWithPushPop) => {
+                format!("stack+=[{}]", Self::address(self.dst0, self.imm1))
+            }
+            Operand::Full(ImmMemHandlerFlags::UseStackWithOffset) => {
+                format!("stack-[{}]", Self::address(self.dst0, self.imm1))
+            }
+            Operand::Full(ImmMemHandlerFlags::UseAbsoluteOnStack) => {
+                format!("stack[{}]", Self::address(self.dst0, self.imm1))
+            }
+            _ => Self::register(self.dst0),
+        }
+    }
+
+    ///
+    /// Whether the first source operand is an immediate.
+    ///
+    fn is_source_immediate(&self) -> bool {
+        matches!(
+            self.variant.src0_operand_type,
+            Operand::RegOrImm(RegOrImmFlags::UseImm16Only)
+                | Operand::Full(ImmMemHandlerFlags::UseImm16Only)
+        )
+    }
+
+    ///
+    /// Whether the first source operand is the zero register, which is omitted by `nop`.
+    ///
+    fn is_source_trivial(&self) -> bool {
+        self.src0 == 0
+            && matches!(
+                self.variant.src0_operand_type,
+                Operand::RegOnly
+                    | Operand::RegOrImm(RegOrImmFlags::UseRegOnly)
+                    | Operand::Full(ImmMemHandlerFlags::UseRegOnly)
+            )
+    }
+
+    ///
+    /// Whether the first destination operand is the zero register, which is omitted by `nop`.
+    ///
+    fn is_destination_trivial(&self) -> bool {
+        self.dst0 == 0
+            && matches!(
+                self.variant.dst0_operand_type,
+                Operand::RegOnly
+                    | Operand::RegOrImm(RegOrImmFlags::UseRegOnly)
+                    | Operand::Full(ImmMemHandlerFlags::UseRegOnly)
+            )
+    }
+
+    ///
+    /// Returns the register operand.
+    ///
+    fn register(index: u8) -> String {
+        format!("r{}", index)
+    }
+
+    ///
+    /// Returns the memory address of the register and immediate offset.
+    ///
+    fn address(register: u8, offset: u16) -> String {
+        match (register, offset) {
+            (0, offset) => offset.to_string(),
+            (register, 0) => Self::register(register),
+            (register, offset) => format!("{} + {}", Self::register(register), offset),
+        }
+    }
+}
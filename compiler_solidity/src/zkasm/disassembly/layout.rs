@@ -0,0 +1,67 @@
+//!
+//! The zkEVM bytecode layout.
+//!
+
+///
+/// The zkEVM bytecode layout.
+///
+/// Used to replace the code and constant addresses with the labels.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Layout {
+    /// The number of the decoded instructions.
+    pub instructions: usize,
+    /// The number of the code words, after which the constants start.
+    pub code_words: usize,
+    /// The total number of the bytecode words.
+    pub words: usize,
+}
+
+impl Layout {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(instructions: usize, code_words: usize, words: usize) -> Self {
+        Self {
+            instructions,
+            code_words,
+            words,
+        }
+    }
+
+    ///
+    /// Returns the code label of the instruction at `index`.
+    ///
+    pub fn code_label(index: usize) -> String {
+        format!(".BB0_{}", index)
+    }
+
+    ///
+    /// Returns the constant label of the constant pool word at `index`.
+    ///
+    pub fn constant_label(index: usize) -> String {
+        format!("CPI0_{}", index)
+    }
+
+    ///
+    /// Returns the code address operand, which is a label if the address is inside the code.
+    ///
+    pub fn code_operand(&self, address: usize) -> String {
+        if address < self.instructions {
+            format!("@{}", Self::code_label(address))
+        } else {
+            address.to_string()
+        }
+    }
+
+    ///
+    /// Returns the code page word operand, which is a label if the word is a constant.
+    ///
+    pub fn constant_operand(&self, word: usize) -> String {
+        if (self.code_words..self.words).contains(&word) {
+            format!("@{}[0]", Self::constant_label(word - self.code_words))
+        } else {
+            format!("code[{}]", word)
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//!
+//! The zkEVM bytecode disassembly.
+//!
+
+pub mod instruction;
+pub mod layout;
+
+use std::collections::BTreeSet;
+
+use num::ToPrimitive;
+use num::Zero;
+
+use self::instruction::condition::Condition;
+use self::instruction::Instruction;
+use self::layout::Layout;
+
+///
+/// The zkEVM bytecode disassembly.
+///
+/// The bytecode consists of the instruction words followed by the constant pool words. The
+/// boundary is the first constant read by the instructions, which is found while decoding.
+///
+#[derive(Debug)]
+pub struct Disassembly {
+    /// The disassembled bytecode.
+    pub bytecode: Vec<u8>,
+    /// The bytecode hash.
+    pub hash: String,
+    /// The decoded instructions.
+    pub instructions: Vec<Instruction>,
+    /// The constant pool words.
+    pub constants: Vec<num::BigUint>,
+    /// The bytecode layout.
+    pub layout: Layout,
+    /// The instructions referenced by the code addresses.
+    pub labels: BTreeSet<usize>,
+    /// The function selectors and the instructions they are dispatched to.
+    pub selectors: Vec<(u32, usize)>,
+}
+
+impl Disassembly {
+    ///
+    /// Disassembles the bytecode.
+    ///
+    pub fn try_from_bytecode(bytecode: Vec<u8>) -> anyhow::Result<Self> {
+        let hash = super::bytecode_hash("disassembled", bytecode.as_slice())?;
+        let words: Vec<[u8; compiler_common::SIZE_FIELD]> = bytecode
+            .chunks(compiler_common::SIZE_FIELD)
+            .map(|word| word.try_into().expect("Always valid"))
+            .collect();
+
+        let mut code_words = words.len();
+        let mut instructions = Vec::with_capacity(code_words * 4);
+        let mut word_index = 0;
+        while word_index < code_words {
+            for raw in words[word_index].chunks(Instruction::SIZE) {
+                let instruction =
+                    Instruction::new(u64::from_be_bytes(raw.try_into().expect("Always valid")));
+                if let Some(address) = instruction.constant_address() {
+                    if address > word_index {
+                        code_words = code_words.min(address);
+                    }
+                }
+                instructions.push(instruction);
+            }
+            word_index += 1;
+        }
+
+        let constants: Vec<num::BigUint> = words[code_words..]
+            .iter()
+            .map(|word| num::BigUint::from_bytes_be(word.as_slice()))
+            .collect();
+        let layout = Layout::new(instructions.len(), code_words, words.len());
+        let labels = instructions
+            .iter()
+            .flat_map(Instruction::code_targets)
+            .filter(|address| *address < instructions.len())
+            .collect();
+        let selectors = Self::selectors(instructions.as_slice(), constants.as_slice(), &layout);
+
+        Ok(Self {
+            bytecode,
+            hash,
+            instructions,
+            constants,
+            layout,
+            labels,
+            selectors,
+        })
+    }
+
+    ///
+    /// Assembles the disassembly text back and checks that the bytecode and its hash are the same.
+    ///
+    pub fn verify(&self) -> anyhow::Result<()> {
+        let contract = super::assemble("disassembled".to_owned(), self.to_string())?;
+
+        if let Some(index) = self
+            .bytecode
+            .chunks(compiler_common::SIZE_FIELD)
+            .zip(contract.build.bytecode.chunks(compiler_common::SIZE_FIELD))
+            .position(|(original, assembled)| original != assembled)
+        {
+            anyhow::bail!(
+                "The disassembly is not lossless: the reassembled bytecode word {} differs",
+                index
+            );
+        }
+        if self.bytecode.len() != contract.build.bytecode.len() {
+            anyhow::bail!(
+                "The disassembly is not lossless: the reassembled bytecode size is {} bytes instead of {}",
+                contract.build.bytecode.len(),
+                self.bytecode.len()
+            );
+        }
+        if self.hash != contract.build.hash {
+            anyhow::bail!(
+                "The reassembled bytecode hash 0x{} does not match the original 0x{}",
+                contract.build.hash,
+                self.hash
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Finds the function selector dispatch, that is, the flag-setting subtractions of 4-byte
+    /// constants followed by the conditional jumps.
+    ///
+    fn selectors(
+        instructions: &[Instruction],
+        constants: &[num::BigUint],
+        layout: &Layout,
+    ) -> Vec<(u32, usize)> {
+        let mut selectors = Vec::new();
+        for (index, instruction) in instructions.iter().enumerate() {
+            let selector = match instruction
+                .compared_constant()
+                .and_then(|word| word.checked_sub(layout.code_words))
+                .and_then(|index| constants.get(index))
+                .filter(|value| !value.is_zero())
+                .and_then(ToPrimitive::to_u32)
+            {
+                Some(selector) => selector,
+                None => continue,
+            };
+
+            for (jump_index, jump) in instructions.iter().enumerate().skip(index + 1).take(3) {
+                if let Some((condition, destination)) = jump.conditional_jump() {
+                    match condition {
+                        Condition::Eq => selectors.push((selector, destination)),
+                        Condition::Ne => selectors.push((selector, jump_index + 1)),
+                        _ => {}
+                    }
+                    break;
+                }
+            }
+        }
+        selectors
+    }
+}
+
+impl std::fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "; {} words: {} code words, {} constant words",
+            self.layout.words,
+            self.layout.code_words,
+            self.constants.len()
+        )?;
+        writeln!(f, "; hash: 0x{}", self.hash)?;
+        if !self.selectors.is_empty() {
+            writeln!(f, "; function selector dispatch:")?;
+            for (selector, destination) in self.selectors.iter() {
+                writeln!(
+                    f,
+                    ";     0x{:08x} -> {}",
+                    selector,
+                    Layout::code_label(*destination)
+                )?;
+            }
+        }
+
+        writeln!(f, "\t.text")?;
+        writeln!(f, "\t.globl\t__entry")?;
+        writeln!(f, "__entry:")?;
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            if self.labels.contains(&index) {
+                writeln!(f, "{}:", Layout::code_label(index))?;
+            }
+            writeln!(f, "\t{}", instruction.to_text(&self.layout))?;
+        }
+
+        if !self.constants.is_empty() {
+            writeln!(f, "\t.rodata.cst32")?;
+            writeln!(f, "\t.p2align\t5")?;
+            for (index, constant) in self.constants.iter().enumerate() {
+                writeln!(f, "{}:", Layout::constant_label(index))?;
+                writeln!(f, "\t.cell\t{}", constant)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::Project;
+
+    use super::Disassembly;
+
+    #[test]
+    fn reassemble() {
+        compiler_llvm_context::initialize_target();
+
+        let yul = r#"
+object "Test" {
+    code {
+        {
+            mstore(64, 128)
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                switch shr(224, calldataload(0))
+                case 0x12345678 {
+                    mstore(0, add(calldataload(4), 0xffffffffffffffffffffffffffffffffffff))
+                    return(0, 32)
+                }
+                default {
+                    revert(0, 0)
+                }
+            }
+        }
+    }
+}
+    "#;
+        let project = Project::try_from_test_yul(yul, &semver::Version::new(0, 8, 17))
+            .expect("Invalid test data");
+        let build = project
+            .compile_all(compiler_llvm_context::OptimizerSettings::cycles(), vec![])
+            .expect("Invalid test data");
+        let bytecode = build
+            .contracts
+            .get("Test")
+            .expect("Always exists")
+            .build
+            .bytecode
+            .clone();
+
+        let disassembly = Disassembly::try_from_bytecode(bytecode.clone()).expect("Always valid");
+        let assembled = crate::zkasm::assemble("Test".to_owned(), disassembly.to_string())
+            .expect("Always valid");
+        assert_eq!(assembled.build.bytecode, bytecode);
+        assert_eq!(assembled.build.hash, disassembly.hash);
+    }
+}
@@ -0,0 +1,184 @@
+//!
+//! The zkEVM assembly.
+//!
+
+pub mod disassembly;
+
+use std::path::Path;
+
+use crate::build::contract::Contract as ContractBuild;
+use crate::source_map::SourceMap;
+
+///
+/// Assembles the zkEVM assembly text of the contract at `path`.
+///
+/// The bytecode hash is computed the same way as for the contracts compiled from Solidity or Yul.
+///
+pub fn assemble(path: String, assembly_text: String) -> anyhow::Result<ContractBuild> {
+    let mut assembly = zkevm_assembly::Assembly::try_from(assembly_text.clone())
+        .map_err(|error| anyhow::anyhow!("The contract `{}` parsing error: {}", path, error))?;
+    let bytecode_words = assembly
+        .compile_to_bytecode()
+        .map_err(|error| anyhow::anyhow!("The contract `{}` assembling error: {}", path, error))?;
+
+    let bytecode: Vec<u8> = bytecode_words.into_iter().flatten().collect();
+    let hash = bytecode_hash(path.as_str(), bytecode.as_slice())?;
+
+    let build = compiler_llvm_context::Build::new(assembly_text, assembly, bytecode, hash);
+    Ok(ContractBuild::new(
+        path.clone(),
+        path,
+        build,
+        SourceMap::default(),
+        None,
+        None,
+        vec![],
+    ))
+}
+
+///
+/// Checks the bytecode size in words against the zkEVM limits.
+///
+/// The bytecode must not exceed the maximum size, and its length in words must be odd to be
+/// hashable.
+///
+pub fn check_size(path: &str, words: usize) -> anyhow::Result<()> {
+    if words > crate::r#const::BYTECODE_MAX_WORDS {
+        anyhow::bail!(
+            "The contract `{}` bytecode size of {} words exceeds the limit of {} words",
+            path,
+            words,
+            crate::r#const::BYTECODE_MAX_WORDS,
+        );
+    }
+    if words % 2 == 0 {
+        anyhow::bail!(
+            "The contract `{}` bytecode size of {} words must be odd",
+            path,
+            words,
+        );
+    }
+
+    Ok(())
+}
+
+///
+/// Checks the bytecode size and returns its hash, computed the same way as for the contracts
+/// compiled from Solidity or Yul.
+///
+pub fn bytecode_hash(path: &str, bytecode: &[u8]) -> anyhow::Result<String> {
+    if bytecode.len() % compiler_common::SIZE_FIELD != 0 {
+        anyhow::bail!(
+            "The contract `{}` bytecode size of {} bytes is not a multiple of the word size of {} bytes",
+            path,
+            bytecode.len(),
+            compiler_common::SIZE_FIELD
+        );
+    }
+    let words: Vec<[u8; compiler_common::SIZE_FIELD]> = bytecode
+        .chunks(compiler_common::SIZE_FIELD)
+        .map(|word| word.try_into().expect("Always valid"))
+        .collect();
+    check_size(path, words.len())?;
+
+    zkevm_assembly::zkevm_opcode_defs::utils::bytecode_to_code_hash(words.as_slice())
+        .map(hex::encode)
+        .map_err(|_error| anyhow::anyhow!("The contract `{}` bytecode hashing error", path))
+}
+
+///
+/// Reads the bytecode from the binary or hexadecimal file at `input`, or from `input` itself
+/// as a hexadecimal string if there is no such file.
+///
+pub fn read_bytecode(input: &str) -> anyhow::Result<Vec<u8>> {
+    let path = Path::new(input);
+    if !path.is_file() {
+        return decode_hexadecimal(input);
+    }
+
+    let data = std::fs::read(path)
+        .map_err(|error| anyhow::anyhow!("File {:?} reading error: {}", path, error))?;
+    match std::str::from_utf8(data.as_slice()) {
+        Ok(text) if is_hexadecimal(text) => decode_hexadecimal(text),
+        _ => Ok(data),
+    }
+}
+
+///
+/// Whether the text is a hexadecimal string, optionally prefixed with `0x`.
+///
+fn is_hexadecimal(text: &str) -> bool {
+    let text = text.trim();
+    let text = text.strip_prefix("0x").unwrap_or(text);
+    !text.is_empty() && text.chars().all(|character| character.is_ascii_hexdigit())
+}
+
+///
+/// Decodes the hexadecimal string, optionally prefixed with `0x`.
+///
+fn decode_hexadecimal(text: &str) -> anyhow::Result<Vec<u8>> {
+    let text = text.trim();
+    let text = text.strip_prefix("0x").unwrap_or(text);
+    hex::decode(text).map_err(|error| anyhow::anyhow!("Invalid hexadecimal bytecode: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::Project;
+
+    #[test]
+    fn assemble() {
+        compiler_llvm_context::initialize_target();
+
+        let yul = r#"
+object "Test" {
+    code {
+        {
+            mstore(64, 128)
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, add(calldataload(0), 1))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+        let project = Project::try_from_test_yul(yul, &semver::Version::new(0, 8, 17))
+            .expect("Invalid test data");
+        let build = project
+            .compile_all(compiler_llvm_context::OptimizerSettings::none(), vec![])
+            .expect("Invalid test data");
+        let expected = &build.contracts.get("Test").expect("Always exists").build;
+
+        let assembled = super::assemble("Test".to_owned(), expected.assembly_text.clone())
+            .expect("Always valid");
+        assert_eq!(assembled.build.bytecode, expected.bytecode);
+        assert_eq!(assembled.build.hash, expected.hash);
+    }
+
+    #[test]
+    fn read_bytecode_hexadecimal() {
+        assert_eq!(
+            super::read_bytecode("0x00ff10").expect("Always valid"),
+            vec![0x00, 0xff, 0x10]
+        );
+        assert_eq!(
+            super::read_bytecode("00ff10\n").expect("Always valid"),
+            vec![0x00, 0xff, 0x10]
+        );
+        assert!(super::read_bytecode("0xzz").is_err());
+    }
+
+    #[test]
+    fn check_size() {
+        assert!(super::check_size("Test", 1).is_ok());
+        assert!(super::check_size("Test", crate::r#const::BYTECODE_MAX_WORDS).is_ok());
+        assert!(super::check_size("Test", 2).is_err());
+        assert!(super::check_size("Test", crate::r#const::BYTECODE_MAX_WORDS + 2).is_err());
+    }
+}
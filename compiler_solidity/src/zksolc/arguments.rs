@@ -46,6 +46,11 @@ pub struct Arguments {
     #[structopt(short = "o", long = "output-dir")]
     pub output_directory: Option<PathBuf>,
 
+    /// If given, writes the build info JSON with the `solc` input, versions, and settings the
+    /// contracts are compiled with, and their bytecode hashes, to the specified directory.
+    #[structopt(long = "build-info")]
+    pub build_info: Option<PathBuf>,
+
     /// Overwrite existing files (used together with -o).
     #[structopt(long = "overwrite")]
     pub overwrite: bool,
@@ -70,7 +75,7 @@ pub struct Arguments {
     pub libraries: Vec<String>,
 
     /// Output a single json document containing the specified information.
-    /// Available arguments: abi, hashes
+    /// Available arguments: abi, hashes, srcmap
     /// Example: solc --combined-json abi,hashes
     #[structopt(long = "combined-json")]
     pub combined_json: Option<String>,
@@ -84,10 +89,67 @@ pub struct Arguments {
     #[structopt(long = "yul")]
     pub yul: bool,
 
+    /// Switch to the EVM legacy assembly mode.
+    /// Reads a JSON file with a single contract assembly, or a map of the contract assemblies
+    /// by their names, and compiles it without `solc`.
+    #[structopt(long = "evmla")]
+    pub evmla: bool,
+
+    /// The `solc` version the EVM legacy assembly has been produced with.
+    /// Only used and required in the EVM legacy assembly mode.
+    #[structopt(long = "solc-version")]
+    pub solc_version: Option<semver::Version>,
+
+    /// Switch to the zkEVM assembly mode.
+    /// Assembles the zkEVM assembly files into bytecode without `solc` and LLVM.
+    #[structopt(long = "zkasm")]
+    pub zkasm: bool,
+
+    /// Disassemble the zkEVM bytecode from a binary or hexadecimal file, or a hexadecimal string.
+    /// The output is checked to assemble back with --zkasm into the same bytecode and hash.
+    #[structopt(long = "disassemble")]
+    pub disassemble: Option<String>,
+
+    /// Recompile the contract specified with --contract from the build info JSON file written
+    /// with --build-info, and compare it with the bytecode specified with --bytecode.
+    #[structopt(long = "verify", parse(from_os_str))]
+    pub verify: Option<PathBuf>,
+
+    /// The contract to verify, as `path:Name`.
+    #[structopt(long = "contract")]
+    pub contract: Option<String>,
+
+    /// The bytecode to verify, as a binary or hexadecimal file, or a hexadecimal string.
+    #[structopt(long = "bytecode")]
+    pub bytecode: Option<String>,
+
     /// Sets the EVM legacy assembly pipeline forcibly.
     #[structopt(long = "force-evmla")]
     pub force_evmla: bool,
 
+    /// Translate the EVM legacy assembly instructions unsupported by zkEVM, e.g. `PC`,
+    /// `CALLCODE`, `EXTCODECOPY` and `SELFDESTRUCT`, with the EVM semantics substitutions instead
+    /// of failing. Use --compat-report to list the substitutions.
+    #[structopt(long = "evmla-lenient")]
+    pub evmla_lenient: bool,
+
+    /// Fail if translating the EVM legacy assembly of a contract requires more Ethereal IR block
+    /// clones per function than specified, naming the most cloned blocks.
+    #[structopt(long = "ethir-clone-budget")]
+    pub ethir_clone_budget: Option<usize>,
+
+    /// Fail if the Ethereal IR blocks are truncated as unreachable because of an invalid stack
+    /// state, instead of reporting the truncations as warnings.
+    #[structopt(long = "strict-ethir")]
+    pub strict_ethir: bool,
+
+    /// Emit the LLVM debug information, that is, the compile unit of each contract and the
+    /// subprograms of its functions, with the positions of the Yul statements and EVM legacy
+    /// assembly instructions attached to the instructions translated from them.
+    /// The debug information is also emitted if the source maps are requested.
+    #[structopt(short = "g", long = "debug-info")]
+    pub debug_info: bool,
+
     /// Output ABI specification of the contracts.
     #[structopt(long = "abi")]
     pub output_abi: bool,
@@ -96,6 +158,16 @@ pub struct Arguments {
     #[structopt(long = "hashes")]
     pub output_hashes: bool,
 
+    /// Output the metadata of the contracts, that is, the `solc` metadata extended with the
+    /// `zksolc` version, the optimizer mode, and the LLVM options.
+    #[structopt(long = "metadata")]
+    pub output_metadata: bool,
+
+    /// Sets the type of the metadata hash appended to the bytecode.
+    /// Available arguments: none, keccak256
+    #[structopt(long = "metadata-hash", default_value = "none")]
+    pub metadata_hash: compiler_solidity::MetadataHash,
+
     /// Output zkEVM assembly of the contracts.
     #[structopt(long = "asm")]
     pub output_assembly: bool,
@@ -116,6 +188,17 @@ pub struct Arguments {
     #[structopt(long = "dump-ethir")]
     pub dump_ethir: bool,
 
+    /// Dump the Ethereal IR block duplication statistics of all contracts: the numbers of blocks,
+    /// clones, and merged clones, the maximum stack depth, and the most cloned blocks.
+    #[structopt(long = "dump-ethir-stats")]
+    pub dump_ethir_stats: bool,
+
+    /// Write the Ethereal IR control flow graph of all contracts, with a node per block clone,
+    /// to the output directory, or the current one if it is not set.
+    /// Available arguments: dot, json
+    #[structopt(long = "dump-ethir-cfg")]
+    pub dump_ethir_cfg: Option<compiler_solidity::EthIRCFGFormat>,
+
     /// Dump the LLVM Intermediate Representation (IR) of all contracts.
     #[structopt(long = "dump-llvm")]
     pub dump_llvm: bool,
@@ -123,6 +206,41 @@ pub struct Arguments {
     /// Dump the zkEVM assembly of all contracts.
     #[structopt(long = "dump-assembly")]
     pub dump_assembly: bool,
+
+    /// Sets the format of errors and warnings printed to stderr.
+    /// Available arguments: human, json
+    /// The json format prints one JSON object per line.
+    #[structopt(long = "error-format", default_value = "human")]
+    pub error_format: compiler_solidity::DiagnosticFormat,
+
+    /// Suppress the zkSync compatibility warnings with the given codes or names.
+    /// A list of warnings can be supplied by separating them with a comma.
+    /// Example: zksolc --suppress-warnings ZK1001,extcodesize
+    #[structopt(long = "suppress-warnings", use_delimiter = true)]
+    pub suppressed_warnings: Vec<compiler_solidity::Warning>,
+
+    /// Report the zkSync compatibility warnings as errors.
+    #[structopt(long = "warnings-as-errors")]
+    pub warnings_as_errors: bool,
+
+    /// Output the list of places where the EVM semantics is changed by the translation,
+    /// e.g. `CODESIZE` replaced with the calldata size, for all contracts.
+    #[structopt(long = "compat-report")]
+    pub compat_report: bool,
+
+    /// Output the bytecode size, the distance to the size limit, the number of factory
+    /// dependencies, and the instruction and constant counts of all contracts.
+    /// Available arguments: table, json
+    #[structopt(long = "size-report")]
+    pub size_report: Option<compiler_solidity::SizeReportFormat>,
+
+    /// Add the instruction counts of each function to the size report.
+    #[structopt(long = "size-report-functions")]
+    pub size_report_functions: bool,
+
+    /// Output the size report difference against the JSON size report of a previous run.
+    #[structopt(long = "size-report-diff")]
+    pub size_report_diff: Option<PathBuf>,
 }
 
 impl Arguments {
@@ -150,6 +268,199 @@ impl Arguments {
             if self.output_hashes {
                 anyhow::bail!("The following options are invalid in Yul mode: --hashes.");
             }
+            if self.dump_ethir_cfg.is_some() {
+                anyhow::bail!("The following options are invalid in Yul mode: --dump-ethir-cfg.");
+            }
+            if self.ethir_clone_budget.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in Yul mode: --ethir-clone-budget."
+                );
+            }
+            if self.strict_ethir {
+                anyhow::bail!("The following options are invalid in Yul mode: --strict-ethir.");
+            }
+            if self.build_info.is_some() {
+                anyhow::bail!("The following options are invalid in Yul mode: --build-info.");
+            }
+        }
+
+        if self.evmla {
+            if self.yul {
+                anyhow::bail!(
+                    "The following options are invalid in EVM legacy assembly mode: --yul."
+                );
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in EVM legacy assembly mode: --combined-json."
+                );
+            }
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in EVM legacy assembly mode: --standard-json."
+                );
+            }
+            if self.output_abi {
+                anyhow::bail!(
+                    "The following options are invalid in EVM legacy assembly mode: --abi."
+                );
+            }
+            if self.output_hashes {
+                anyhow::bail!(
+                    "The following options are invalid in EVM legacy assembly mode: --hashes."
+                );
+            }
+            if self.build_info.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in EVM legacy assembly mode: --build-info."
+                );
+            }
+            if self.solc_version.is_none() {
+                anyhow::bail!(
+                    "The following options are required in EVM legacy assembly mode: --solc-version."
+                );
+            }
+        } else if self.solc_version.is_some() {
+            anyhow::bail!(
+                "The following options are only valid in EVM legacy assembly mode: --solc-version."
+            );
+        }
+
+        if self.zkasm {
+            if self.yul {
+                anyhow::bail!("The following options are invalid in zkEVM assembly mode: --yul.");
+            }
+            if self.evmla {
+                anyhow::bail!("The following options are invalid in zkEVM assembly mode: --evmla.");
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in zkEVM assembly mode: --combined-json."
+                );
+            }
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in zkEVM assembly mode: --standard-json."
+                );
+            }
+            if self.output_abi {
+                anyhow::bail!("The following options are invalid in zkEVM assembly mode: --abi.");
+            }
+            if self.output_hashes {
+                anyhow::bail!(
+                    "The following options are invalid in zkEVM assembly mode: --hashes."
+                );
+            }
+            if self.build_info.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in zkEVM assembly mode: --build-info."
+                );
+            }
+        }
+
+        if self.disassemble.is_some() {
+            if self.zkasm {
+                anyhow::bail!("The following options are invalid in disassembly mode: --zkasm.");
+            }
+            if self.yul {
+                anyhow::bail!("The following options are invalid in disassembly mode: --yul.");
+            }
+            if self.evmla {
+                anyhow::bail!("The following options are invalid in disassembly mode: --evmla.");
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in disassembly mode: --combined-json."
+                );
+            }
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in disassembly mode: --standard-json."
+                );
+            }
+            if !self.input_files.is_empty() {
+                anyhow::bail!(
+                    "The input files are invalid in disassembly mode, pass the bytecode to --disassemble."
+                );
+            }
+        }
+
+        if self.verify.is_some() {
+            if self.yul {
+                anyhow::bail!("The following options are invalid in verification mode: --yul.");
+            }
+            if self.evmla {
+                anyhow::bail!("The following options are invalid in verification mode: --evmla.");
+            }
+            if self.zkasm {
+                anyhow::bail!("The following options are invalid in verification mode: --zkasm.");
+            }
+            if self.disassemble.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in verification mode: --disassemble."
+                );
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in verification mode: --combined-json."
+                );
+            }
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in verification mode: --standard-json."
+                );
+            }
+            if !self.input_files.is_empty() {
+                anyhow::bail!(
+                    "The input files are invalid in verification mode, pass the build info to --verify."
+                );
+            }
+            if self.contract.is_none() {
+                anyhow::bail!(
+                    "The following options are required in verification mode: --contract."
+                );
+            }
+            if self.bytecode.is_none() {
+                anyhow::bail!(
+                    "The following options are required in verification mode: --bytecode."
+                );
+            }
+        } else if self.contract.is_some() {
+            anyhow::bail!("The following options are only valid in verification mode: --contract.");
+        } else if self.bytecode.is_some() {
+            anyhow::bail!("The following options are only valid in verification mode: --bytecode.");
+        }
+
+        if self.compat_report && self.standard_json {
+            anyhow::bail!(
+                "The following options are invalid in Standard JSON mode: --compat-report."
+            );
+        }
+        if self.compat_report && self.combined_json.is_some() {
+            anyhow::bail!(
+                "The following options are invalid in combined JSON mode: --compat-report."
+            );
+        }
+
+        if self.size_report.is_some() {
+            if self.standard_json {
+                anyhow::bail!(
+                    "The following options are invalid in Standard JSON mode: --size-report."
+                );
+            }
+            if self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The following options are invalid in combined JSON mode: --size-report."
+                );
+            }
+        } else if self.size_report_functions {
+            anyhow::bail!(
+                "The following options are only valid with --size-report: --size-report-functions."
+            );
+        } else if self.size_report_diff.is_some() {
+            anyhow::bail!(
+                "The following options are only valid with --size-report: --size-report-diff."
+            );
         }
 
         Ok(())
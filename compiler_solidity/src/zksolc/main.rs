@@ -4,6 +4,10 @@
 
 pub mod arguments;
 
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
 use self::arguments::Arguments;
 
 #[cfg(target_env = "musl")]
@@ -14,10 +18,28 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 /// The application entry point.
 ///
 fn main() {
-    std::process::exit(match main_inner() {
+    let arguments = Arguments::new();
+    let error_format = arguments.error_format;
+
+    std::process::exit(match main_inner(arguments) {
         Ok(()) => compiler_common::EXIT_CODE_SUCCESS,
         Err(error) => {
-            eprintln!("{}", error);
+            match error_format {
+                compiler_solidity::DiagnosticFormat::Human => eprintln!("{}", error),
+                compiler_solidity::DiagnosticFormat::JSON => {
+                    match error.downcast_ref::<compiler_solidity::DiagnosticCollection>() {
+                        Some(collection) => {
+                            for diagnostic in collection.diagnostics.iter() {
+                                eprintln!("{}", diagnostic.to_json());
+                            }
+                        }
+                        None => eprintln!(
+                            "{}",
+                            compiler_solidity::Diagnostic::new_error(error.to_string()).to_json()
+                        ),
+                    }
+                }
+            }
             compiler_common::EXIT_CODE_FAILURE
         }
     })
@@ -26,13 +48,17 @@ fn main() {
 ///
 /// The auxiliary `main` function to facilitate the `?` error conversion operator.
 ///
-fn main_inner() -> anyhow::Result<()> {
-    let mut arguments = Arguments::new();
+fn main_inner(mut arguments: Arguments) -> anyhow::Result<()> {
     arguments.validate()?;
 
+    if !atty::is(atty::Stream::Stderr) {
+        colored::control::set_override(false);
+    }
+
     let dump_flags = compiler_solidity::DumpFlag::from_booleans(
         arguments.dump_yul,
         arguments.dump_ethir,
+        arguments.dump_ethir_stats,
         arguments.dump_evm,
         arguments.dump_llvm,
         arguments.dump_assembly,
@@ -42,11 +68,97 @@ fn main_inner() -> anyhow::Result<()> {
         *path = path.canonicalize()?;
     }
 
+    if let Some(input) = arguments.disassemble {
+        let bytecode = compiler_solidity::read_bytecode(input.as_str())?;
+        let disassembly = compiler_solidity::ZkAsmDisassembly::try_from_bytecode(bytecode)?;
+        print!("{}", disassembly);
+        disassembly.verify()?;
+        return Ok(());
+    }
+
+    if let Some(path) = arguments.verify {
+        let build_info = compiler_solidity::BuildInfo::try_from_path(path.as_path())?;
+        let bytecode =
+            compiler_solidity::read_bytecode(arguments.bytecode.expect("Always exists").as_str())?;
+        let solc = compiler_solidity::SolcCompiler::new(arguments.solc.unwrap_or_else(|| {
+            compiler_solidity::SolcCompiler::DEFAULT_EXECUTABLE_NAME.to_owned()
+        }));
+
+        compiler_llvm_context::initialize_target();
+        if !build_info.llvm_options.is_empty() {
+            let llvm_options = Vec::from_iter(build_info.llvm_options.iter().map(String::as_str));
+            inkwell::support::parse_command_line_options(
+                llvm_options.len() as i32,
+                llvm_options.as_slice(),
+                "",
+            );
+        }
+
+        let verification = compiler_solidity::Verification::try_from_build_info(
+            &build_info,
+            &solc,
+            arguments.contract.expect("Always exists").as_str(),
+            bytecode,
+            arguments.base_path,
+            arguments.include_paths,
+            arguments.allow_paths,
+        )?;
+        if !verification.is_verified() {
+            anyhow::bail!("{}", verification);
+        }
+        println!("{}", verification);
+        return Ok(());
+    }
+
+    if arguments.zkasm {
+        if arguments.input_files.is_empty() {
+            anyhow::bail!("The input file is missing");
+        }
+
+        let build = compiler_solidity::Build::try_from_zkasm(arguments.input_files.as_slice())?;
+        print_size_report(
+            &build,
+            arguments.size_report,
+            arguments.size_report_functions,
+            arguments.size_report_diff.as_deref(),
+        )?;
+        if let Some(output_directory) = arguments.output_directory {
+            std::fs::create_dir_all(&output_directory)?;
+            build.write_to_directory(
+                &output_directory,
+                arguments.output_assembly,
+                true,
+                false,
+                false,
+                arguments.overwrite,
+            )?;
+            eprintln!(
+                "Assembling successful. Artifact(s) can be found in directory {:?}.",
+                output_directory
+            );
+        } else {
+            for (path, contract) in build.contracts.into_iter() {
+                println!(
+                    "Contract `{}` bytecode: 0x{}",
+                    path,
+                    hex::encode(contract.build.bytecode)
+                );
+                println!("Contract `{}` hash: 0x{}", path, contract.build.hash);
+            }
+        }
+        return Ok(());
+    }
+
     let solc =
         compiler_solidity::SolcCompiler::new(arguments.solc.unwrap_or_else(|| {
             compiler_solidity::SolcCompiler::DEFAULT_EXECUTABLE_NAME.to_owned()
         }));
-    let solc_version = solc.version()?;
+    let solc_version = match arguments.solc_version {
+        Some(ref version) if arguments.evmla => {
+            compiler_solidity::SolcVersion::new(version.to_string(), version.to_owned())
+        }
+        _ => solc.version()?,
+    };
     if solc_version.default > compiler_solidity::SolcCompiler::LAST_SUPPORTED_VERSION {
         anyhow::bail!(
             "solc versions >{} are not supported yet, found {}",
@@ -66,9 +178,12 @@ fn main_inner() -> anyhow::Result<()> {
     };
 
     compiler_llvm_context::initialize_target();
-    if let Some(llvm_options) = arguments.llvm_options {
-        let llvm_options = shell_words::split(llvm_options.as_str())
-            .map_err(|error| anyhow::anyhow!("LLVM options parsing error: {}", error))?;
+    let llvm_options = match arguments.llvm_options {
+        Some(llvm_options) => shell_words::split(llvm_options.as_str())
+            .map_err(|error| anyhow::anyhow!("LLVM options parsing error: {}", error))?,
+        None => Vec::new(),
+    };
+    if !llvm_options.is_empty() {
         let llvm_options = Vec::from_iter(llvm_options.iter().map(String::as_str));
         inkwell::support::parse_command_line_options(
             llvm_options.len() as i32,
@@ -87,14 +202,71 @@ fn main_inner() -> anyhow::Result<()> {
             ),
         };
 
-        let project =
+        let mut project =
             compiler_solidity::Project::try_from_default_yul(&path, &solc_version.default)?;
+        project.set_evmla_lenient(arguments.evmla_lenient);
+        project.set_debug_info(arguments.debug_info);
+        project.set_optimized(arguments.optimize);
+        project.set_llvm_options(llvm_options.clone());
+        project.set_metadata_hash(arguments.metadata_hash);
         let optimizer_settings = if arguments.optimize {
             compiler_llvm_context::OptimizerSettings::cycles()
         } else {
             compiler_llvm_context::OptimizerSettings::none()
         };
+        if arguments.compat_report {
+            print!("{}", project.compat_report());
+        }
         project.compile_all(optimizer_settings, dump_flags)
+    } else if arguments.evmla {
+        let path = match arguments.input_files.len() {
+            1 => arguments.input_files.remove(0),
+            0 => anyhow::bail!("The input file is missing"),
+            length => anyhow::bail!(
+                "Only one input file is allowed in the EVM legacy assembly mode, but found {}",
+                length
+            ),
+        };
+
+        let libraries =
+            compiler_solidity::SolcStandardJsonInputSettings::parse_libraries(arguments.libraries)?;
+        let mut project =
+            compiler_solidity::Project::try_from_evmla(&path, &solc_version.default, libraries)?;
+        project.set_evmla_lenient(arguments.evmla_lenient);
+        project.set_debug_info(arguments.debug_info);
+        project.set_ethir_clone_budget(arguments.ethir_clone_budget);
+        project.set_ethir_strict(arguments.strict_ethir);
+        project.set_optimized(arguments.optimize);
+        project.set_llvm_options(llvm_options.clone());
+        project.set_metadata_hash(arguments.metadata_hash);
+        project.set_warning_settings(compiler_solidity::WarningSettings::new(
+            arguments.suppressed_warnings,
+            arguments.warnings_as_errors,
+        ));
+        project.set_ethir_cfg_dump(arguments.dump_ethir_cfg.map(|format| {
+            let directory = arguments
+                .output_directory
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("."));
+            (format, directory)
+        }));
+        let optimizer_settings = if arguments.optimize {
+            compiler_llvm_context::OptimizerSettings::cycles()
+        } else {
+            compiler_llvm_context::OptimizerSettings::none()
+        };
+        if arguments.compat_report {
+            print!("{}", project.compat_report());
+        }
+        let build = project.compile_all(optimizer_settings, dump_flags)?;
+        for warning in build
+            .contracts
+            .values()
+            .flat_map(|contract| contract.warnings.iter())
+        {
+            print_diagnostic(warning, &BTreeMap::new(), arguments.error_format);
+        }
+        Ok(build)
     } else {
         let output_selection =
             compiler_solidity::SolcStandardJsonInputSettings::get_output_selection(
@@ -105,9 +277,22 @@ fn main_inner() -> anyhow::Result<()> {
                     .collect(),
                 pipeline,
             );
+        let mut is_source_map = arguments
+            .combined_json
+            .as_deref()
+            .map(|combined_json| {
+                combined_json
+                    .split(',')
+                    .any(|selector| matches!(selector, "srcmap" | "srcmap-runtime"))
+            })
+            .unwrap_or_default();
         let solc_input = if arguments.standard_json {
             let mut input: compiler_solidity::SolcStandardJsonInput =
                 serde_json::from_reader(std::io::BufReader::new(std::io::stdin()))?;
+            is_source_map =
+                compiler_solidity::SolcStandardJsonInputSettings::is_source_map_requested(
+                    &input.settings.output_selection,
+                );
             input.settings.output_selection = output_selection;
             input
         } else {
@@ -120,12 +305,26 @@ fn main_inner() -> anyhow::Result<()> {
             )?
         };
 
+        let source_code_files: BTreeMap<String, String> = solc_input
+            .sources
+            .iter()
+            .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+            .collect();
         let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
         let optimize = if arguments.standard_json {
             solc_input.settings.optimizer.enabled
         } else {
             arguments.optimize
         };
+        let mut suppressed_warnings = arguments.suppressed_warnings;
+        for warning in solc_input.settings.suppressed_warnings.iter().flatten() {
+            suppressed_warnings.push(warning.parse()?);
+        }
+        let warning_settings = compiler_solidity::WarningSettings::new(
+            suppressed_warnings,
+            arguments.warnings_as_errors,
+        );
+        let build_info_input = arguments.build_info.as_ref().map(|_| solc_input.clone());
         let mut solc_output = solc.standard_json(
             solc_input,
             arguments.base_path,
@@ -145,7 +344,7 @@ fn main_inner() -> anyhow::Result<()> {
                 }
 
                 if !arguments.standard_json && arguments.combined_json.is_none() {
-                    eprintln!("{}", error);
+                    print_diagnostic(error, &source_code_files, arguments.error_format);
                 }
             }
 
@@ -154,18 +353,103 @@ fn main_inner() -> anyhow::Result<()> {
             }
         }
 
-        let project = solc_output.try_to_project(
+        let solc_errors_count = solc_output
+            .errors
+            .as_ref()
+            .map(Vec::len)
+            .unwrap_or_default();
+        let mut project = match solc_output.try_to_project(
+            &source_code_files,
             libraries,
             pipeline,
             &solc_version.default,
             dump_flags.as_slice(),
-        )?;
+            &warning_settings,
+        ) {
+            Ok(project) => project,
+            Err(_error)
+                if arguments.standard_json
+                    && solc_output
+                        .errors
+                        .iter()
+                        .flatten()
+                        .any(|error| error.severity.as_str() == "error") =>
+            {
+                serde_json::to_writer(std::io::stdout(), &solc_output)?;
+                return Ok(());
+            }
+            Err(_error)
+                if arguments.error_format == compiler_solidity::DiagnosticFormat::JSON
+                    && !arguments.standard_json
+                    && arguments.combined_json.is_none()
+                    && solc_output
+                        .errors
+                        .iter()
+                        .flatten()
+                        .skip(solc_errors_count)
+                        .any(|error| error.severity.as_str() == "error") =>
+            {
+                for error in solc_output.errors.iter().flatten().skip(solc_errors_count) {
+                    print_diagnostic(error, &source_code_files, arguments.error_format);
+                }
+                anyhow::bail!("Error(s) found. Compilation aborted");
+            }
+            Err(error) => return Err(error),
+        };
+        if !arguments.standard_json && arguments.combined_json.is_none() {
+            for error in solc_output.errors.iter().flatten().skip(solc_errors_count) {
+                print_diagnostic(error, &source_code_files, arguments.error_format);
+            }
+        }
         let optimizer_settings = if optimize {
             compiler_llvm_context::OptimizerSettings::cycles()
         } else {
             compiler_llvm_context::OptimizerSettings::none()
         };
+        project.set_evmla_lenient(arguments.evmla_lenient);
+        project.set_debug_info(arguments.debug_info);
+        project.set_source_map(is_source_map);
+        project.set_ethir_clone_budget(arguments.ethir_clone_budget);
+        project.set_ethir_strict(arguments.strict_ethir);
+        project.set_optimized(optimize);
+        project.set_llvm_options(llvm_options.clone());
+        project.set_metadata_hash(arguments.metadata_hash);
+        project.set_warning_settings(warning_settings);
+        project.set_ethir_cfg_dump(arguments.dump_ethir_cfg.map(|format| {
+            let directory = arguments
+                .output_directory
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("."));
+            (format, directory)
+        }));
+        if arguments.compat_report {
+            print!("{}", project.compat_report());
+        }
         let build = project.compile_all(optimizer_settings, dump_flags)?;
+        if let (Some(directory), Some(input)) = (arguments.build_info.as_ref(), build_info_input) {
+            std::fs::create_dir_all(directory)?;
+            let build_info = build.build_info(
+                input,
+                &solc_version,
+                pipeline,
+                optimize,
+                llvm_options.clone(),
+                arguments.metadata_hash,
+            );
+            let path = build_info.write_to_directory(directory)?;
+            if !arguments.standard_json {
+                eprintln!("Build info written to {:?}.", path);
+            }
+        }
+        if !arguments.standard_json {
+            for warning in build
+                .contracts
+                .values()
+                .flat_map(|contract| contract.warnings.iter())
+            {
+                print_diagnostic(warning, &source_code_files, arguments.error_format);
+            }
+        }
         if arguments.standard_json {
             build.write_to_standard_json(&mut solc_output, &solc_version, &zksolc_version)?;
             serde_json::to_writer(std::io::stdout(), &solc_output)?;
@@ -173,6 +457,12 @@ fn main_inner() -> anyhow::Result<()> {
         }
         Ok(build)
     }?;
+    print_size_report(
+        &build,
+        arguments.size_report,
+        arguments.size_report_functions,
+        arguments.size_report_diff.as_deref(),
+    )?;
 
     let combined_json = if let Some(combined_json) = arguments.combined_json {
         Some(solc.combined_json(arguments.input_files.as_slice(), combined_json.as_str())?)
@@ -192,6 +482,7 @@ fn main_inner() -> anyhow::Result<()> {
                 arguments.output_assembly,
                 arguments.output_binary,
                 arguments.output_abi,
+                arguments.output_metadata,
                 arguments.overwrite,
             )?;
         }
@@ -210,6 +501,7 @@ fn main_inner() -> anyhow::Result<()> {
         || arguments.output_binary
         || arguments.output_hashes
         || arguments.output_abi
+        || arguments.output_metadata
     {
         for (path, contract) in build.contracts.into_iter() {
             if arguments.output_assembly {
@@ -225,6 +517,9 @@ fn main_inner() -> anyhow::Result<()> {
                     hex::encode(contract.build.bytecode)
                 );
             }
+            if let (true, Some(metadata)) = (arguments.output_metadata, contract.metadata) {
+                println!("Contract `{}` metadata: {}", path, metadata);
+            }
         }
 
         if arguments.output_abi || arguments.output_hashes {
@@ -235,9 +530,68 @@ fn main_inner() -> anyhow::Result<()> {
             )?;
             print!("{}", extra_output);
         }
-    } else {
+    } else if arguments.size_report.is_none() {
         eprintln!("Compiler run successful. No output requested. Use --asm and --bin flags.");
     }
 
     Ok(())
 }
+
+///
+/// Prints the size report, or its difference against the previous one, if requested.
+///
+fn print_size_report(
+    build: &compiler_solidity::Build,
+    format: Option<compiler_solidity::SizeReportFormat>,
+    with_functions: bool,
+    previous_path: Option<&Path>,
+) -> anyhow::Result<()> {
+    let format = match format {
+        Some(format) => format,
+        None => return Ok(()),
+    };
+
+    let size_report = build.size_report(with_functions);
+    let output = match previous_path {
+        Some(path) => {
+            let previous = compiler_solidity::SizeReport::try_from_path(path)?;
+            let diff = size_report.diff(&previous);
+            match format {
+                compiler_solidity::SizeReportFormat::Table => diff.to_string(),
+                compiler_solidity::SizeReportFormat::JSON => diff.to_json(),
+            }
+        }
+        None => match format {
+            compiler_solidity::SizeReportFormat::Table => size_report.to_string(),
+            compiler_solidity::SizeReportFormat::JSON => size_report.to_json(),
+        },
+    };
+    println!("{}", output.trim_end());
+
+    Ok(())
+}
+
+///
+/// Prints the error or warning to stderr in the specified format. The JSON diagnostic includes
+/// the source code snippet if the file it points to is in `source_code_files`.
+///
+fn print_diagnostic(
+    error: &compiler_solidity::SolcStandardJsonOutputError,
+    source_code_files: &BTreeMap<String, String>,
+    format: compiler_solidity::DiagnosticFormat,
+) {
+    match format {
+        compiler_solidity::DiagnosticFormat::Human => eprintln!("{}", error),
+        compiler_solidity::DiagnosticFormat::JSON => {
+            let source_code = error
+                .source_location
+                .as_ref()
+                .and_then(|location| source_code_files.get(location.file.as_str()))
+                .map(String::as_str);
+            eprintln!(
+                "{}",
+                compiler_solidity::Diagnostic::new_solc(error, source_code).to_json()
+            );
+        }
+    }
+}